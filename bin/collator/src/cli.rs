@@ -53,6 +53,19 @@ pub struct Cli {
     /// Proposer's soft deadline in percents of block size
     #[clap(long, default_value = "50")]
     pub proposer_soft_deadline_percent: u8,
+
+    /// JSON file with genesis accounts used to build the `astar-dev` chain specification.
+    #[clap(long, value_name = "FILE")]
+    pub genesis_accounts: Option<PathBuf>,
+
+    /// Build the `astar-dev` chain specification for a single collator, `Alice`, with a single
+    /// desired candidate and an affordable candidacy bond.
+    #[clap(long, conflicts_with = "genesis_accounts")]
+    pub dev_single_collator: bool,
+
+    /// Print the blake2-256 hash of the chain specification selected via `--chain` and exit.
+    #[clap(long)]
+    pub print_spec_hash: bool,
 }
 
 /// Possible subcommands of the main binary.
@@ -98,6 +111,22 @@ pub enum Subcommand {
     /// Export the genesis wasm of the parachain.
     ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+    /// Print the accounts endowed in the genesis of an Astar chain specification.
+    PrintGenesisAccounts(PrintGenesisAccountsCmd),
+
+    /// Check the genesis invariants of an Astar chain specification file.
+    CheckSpec(CheckSpecCmd),
+
+    /// Print the hex encoded genesis head of an Astar chain specification, to register the
+    /// parachain on the relay chain.
+    ExportGenesisHead(ExportGenesisHeadCmd),
+
+    /// Print the chain specifications built into the collator, as JSON.
+    ListChains,
+
+    /// Print a commented TOML template of an Astar chain specification, see `spec_from_toml`.
+    ChainSpecTemplate,
+
     /// The custom benchmark subcommmand benchmarking runtime pallets.
     #[cfg(feature = "runtime-benchmarks")]
     #[clap(name = "benchmark", about = "Benchmark runtime pallets.")]
@@ -109,6 +138,30 @@ pub enum Subcommand {
     TryRuntime(try_runtime_cli::TryRuntimeCmd),
 }
 
+/// Print the accounts endowed in genesis, sorted by descending balance, and their total balance.
+#[derive(Debug, clap::Parser)]
+pub struct PrintGenesisAccountsCmd {
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: sc_cli::SharedParams,
+}
+
+/// Print the `0x` prefixed, SCALE encoded, genesis header of the parachain.
+#[derive(Debug, clap::Parser)]
+pub struct ExportGenesisHeadCmd {
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: sc_cli::SharedParams,
+}
+
+/// Check the genesis invariants of a (non-raw) Astar chain specification, reporting all the
+/// violations.
+#[derive(Debug, clap::Parser)]
+pub struct CheckSpecCmd {
+    /// Path of the chain specification JSON file.
+    pub path: PathBuf,
+}
+
 #[derive(Debug)]
 #[allow(missing_docs)]
 pub struct RelayChainCli {
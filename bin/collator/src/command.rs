@@ -38,7 +38,7 @@ use sc_service::{
     config::{BasePath, PrometheusConfig},
     PartialComponents,
 };
-use sp_core::hexdisplay::HexDisplay;
+use sp_core::{crypto::Ss58Codec, hexdisplay::HexDisplay};
 use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::traits::Block as BlockT;
 use std::net::SocketAddr;
@@ -87,6 +87,7 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, St
     Ok(match id {
         "dev" => Box::new(development_config()),
         "astar-dev" => Box::new(chain_spec::astar::get_chain_spec()),
+        "astar-local" => Box::new(chain_spec::astar::get_local_chain_spec()),
         "shibuya-dev" => Box::new(chain_spec::shibuya::get_chain_spec()),
         "shiden-dev" => Box::new(chain_spec::shiden::get_chain_spec()),
         "astar" => Box::new(chain_spec::AstarChainSpec::from_json_bytes(
@@ -113,6 +114,62 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, St
     })
 }
 
+/// Ids of the chain specifications built into the collator, see [`load_spec`].
+const BUILTIN_SPECS: [&str; 8] = [
+    "dev",
+    "astar-dev",
+    "astar-local",
+    "shibuya-dev",
+    "shiden-dev",
+    "astar",
+    "shiden",
+    "shibuya",
+];
+
+/// Description of a chain specification built into the collator.
+#[derive(Debug, serde::Serialize)]
+pub struct SpecInfo {
+    /// Value to pass to `--chain`.
+    pub id: String,
+    /// Name of the chain.
+    pub name: String,
+    /// Para id, `None` for chains which aren't parachains.
+    pub para_id: Option<u32>,
+    /// Relay chain, `None` for chains which aren't parachains.
+    pub relay_chain: Option<String>,
+    /// Type of the chain.
+    pub chain_type: sc_service::ChainType,
+}
+
+/// All the chain specifications built into the collator.
+pub fn available_specs() -> std::result::Result<Vec<SpecInfo>, String> {
+    BUILTIN_SPECS
+        .into_iter()
+        .map(|id| {
+            let spec = load_spec(id)?;
+            let extensions = chain_spec::Extensions::try_get(&*spec);
+            Ok(SpecInfo {
+                id: id.into(),
+                name: spec.name().into(),
+                para_id: extensions.map(|e| e.para_id),
+                relay_chain: extensions.map(|e| e.relay_chain.clone()),
+                chain_type: spec.chain_type(),
+            })
+        })
+        .collect()
+}
+
+/// `0x` prefixed hex of the genesis head of an Astar chain specification, as required to register
+/// the parachain on the relay chain.
+pub fn genesis_head_hex(spec: &dyn ChainSpec) -> std::result::Result<String, String> {
+    if !spec.is_astar() {
+        return Err("Only Astar chain specifications are supported".into());
+    }
+
+    let head = chain_spec::astar::genesis_head(spec)?;
+    Ok(format!("0x{}", HexDisplay::from(&head)))
+}
+
 impl SubstrateCli for Cli {
     fn impl_name() -> String {
         "Astar Collator".into()
@@ -145,7 +202,15 @@ impl SubstrateCli for Cli {
     }
 
     fn load_spec(&self, id: &str) -> std::result::Result<Box<dyn sc_service::ChainSpec>, String> {
-        load_spec(id)
+        match (id, &self.genesis_accounts) {
+            ("astar-dev", Some(path)) => Ok(Box::new(
+                chain_spec::astar::get_chain_spec_from_file(path).map_err(|e| e.to_string())?,
+            )),
+            ("astar-dev", None) if self.dev_single_collator => {
+                Ok(Box::new(chain_spec::astar::get_single_collator_chain_spec()))
+            }
+            _ => load_spec(id),
+        }
     }
 
     fn native_runtime_version(chain_spec: &Box<dyn ChainSpec>) -> &'static RuntimeVersion {
@@ -474,6 +539,55 @@ pub fn run() -> Result<()> {
                 cmd.run(&*spec)
             })
         }
+        Some(Subcommand::PrintGenesisAccounts(cmd)) => {
+            let spec = cli.load_spec(&cmd.shared_params.chain.clone().unwrap_or_default())?;
+            if !spec.is_astar() {
+                return Err("Only Astar chain specifications are supported".into());
+            }
+
+            let (balances, total) = chain_spec::astar::genesis_allocation(&*spec)?;
+            let ss58_prefix = u16::from(astar_runtime::SS58Prefix::get()).into();
+            for (who, balance) in balances {
+                println!("{} {}", who.to_ss58check_with_version(ss58_prefix), balance);
+            }
+            println!("Total {}", total);
+
+            Ok(())
+        }
+        Some(Subcommand::CheckSpec(cmd)) => {
+            let spec = chain_spec::AstarChainSpec::from_json_file(cmd.path.clone())?;
+            if !spec.is_astar() {
+                return Err("Only Astar chain specifications are supported".into());
+            }
+
+            match chain_spec::astar::validate_spec(&spec) {
+                Ok(()) => {
+                    println!("Chain specification is valid");
+                    Ok(())
+                }
+                Err(errors) => {
+                    for error in &errors {
+                        println!("{}", error);
+                    }
+                    Err(format!("Chain specification has {} error(s)", errors.len()).into())
+                }
+            }
+        }
+        Some(Subcommand::ExportGenesisHead(cmd)) => {
+            let spec = cli.load_spec(&cmd.shared_params.chain.clone().unwrap_or_default())?;
+            println!("{}", genesis_head_hex(&*spec)?);
+            Ok(())
+        }
+        Some(Subcommand::ListChains) => {
+            let specs = serde_json::to_string_pretty(&available_specs()?)
+                .map_err(|e| format!("Error serializing chain specifications: {}", e))?;
+            println!("{}", specs);
+            Ok(())
+        }
+        Some(Subcommand::ChainSpecTemplate) => {
+            print!("{}", chain_spec::astar::SPEC_TOML_TEMPLATE);
+            Ok(())
+        }
         Some(Subcommand::Key(cmd)) => cmd.run(&cli),
         Some(Subcommand::Sign(cmd)) => cmd.run(),
         Some(Subcommand::Verify(cmd)) => cmd.run(),
@@ -842,6 +956,14 @@ pub fn run() -> Result<()> {
                 })
             }
         }
+        None if cli.print_spec_hash => {
+            let spec =
+                cli.load_spec(&cli.run.base.shared_params.chain.clone().unwrap_or_default())?;
+            let hash = chain_spec::astar::spec_blake2_256(&*spec)?;
+            println!("0x{}", HexDisplay::from(&hash));
+
+            Ok(())
+        }
         None => {
             let runner = cli.create_runner(&cli.run.normalize())?;
             let collator_options = cli.run.collator_options();
@@ -1083,3 +1205,68 @@ impl CliConfiguration<Self> for RelayChainCli {
         self.base.base.telemetry_endpoints(chain_spec)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Decode;
+    use sp_runtime::traits::Header as HeaderT;
+
+    #[test]
+    fn available_specs_describe_builtin_specs() {
+        let specs = available_specs().unwrap();
+        assert_eq!(
+            specs
+                .iter()
+                .map(|spec| spec.id.as_str())
+                .collect::<Vec<_>>(),
+            BUILTIN_SPECS
+        );
+
+        let astar = specs.iter().find(|spec| spec.id == "astar-dev").unwrap();
+        assert_eq!(astar.para_id, Some(2006));
+        assert_eq!(astar.relay_chain.as_deref(), Some("tokyo"));
+        assert_eq!(astar.chain_type, sc_service::ChainType::Development);
+    }
+
+    #[test]
+    fn genesis_head_is_exported_as_hex() {
+        let spec = load_spec("astar-dev").unwrap();
+        let head = genesis_head_hex(&*spec).unwrap();
+
+        let bytes = sp_core::bytes::from_hex(&head).unwrap();
+        assert!(head.starts_with("0x"));
+        // Parent hash, compact number, state & extrinsics roots and an empty digest.
+        assert_eq!(bytes.len(), 32 + 1 + 32 + 32 + 1);
+        let header = Header::decode(&mut &bytes[..]).unwrap();
+        assert_eq!(
+            *header.state_root(),
+            chain_spec::astar::genesis_state_root(&*spec).unwrap()
+        );
+
+        assert!(genesis_head_hex(&*load_spec("shiden-dev").unwrap()).is_err());
+    }
+
+    #[test]
+    fn dev_single_collator_spec_is_loaded() {
+        let cli = Cli::from_iter(["astar-collator", "--dev-single-collator"]);
+        let spec = cli.load_spec("astar-dev").unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&spec.as_json(false).unwrap()).unwrap();
+        let runtime = &json["genesis"]["runtime"];
+        let invulnerables = runtime["collatorSelection"]["invulnerables"]
+            .as_array()
+            .unwrap();
+        assert_eq!(invulnerables.len(), 1);
+        assert_eq!(runtime["session"]["keys"].as_array().unwrap().len(), 1);
+
+        // The accounts file provides the collators instead
+        assert!(Cli::try_from_iter([
+            "astar-collator",
+            "--dev-single-collator",
+            "--genesis-accounts",
+            "accounts.json",
+        ])
+        .is_err());
+    }
+}
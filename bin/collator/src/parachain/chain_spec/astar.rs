@@ -18,31 +18,249 @@
 
 //! Astar chain specifications.
 
+use astar_primitives::{dapp_staking::SmartContract, AssetId, BlockNumber};
 use astar_runtime::{
-    wasm_binary_unwrap, AccountId, AuraId, Balance, DappStakingConfig, EVMConfig, InflationConfig,
-    InflationParameters, ParachainInfoConfig, Precompiles, Signature, SystemConfig, TierThreshold,
+    wasm_binary_unwrap, AccountId, AuraId, Balance, DappStakingConfig, ExistentialDeposit,
+    InflationParameters, Precompiles, RuntimeCall, SS58Prefix, TierThreshold, TreasuryPalletId,
     ASTR,
 };
-use cumulus_primitives_core::ParaId;
-use sc_service::ChainType;
-use sp_core::{sr25519, Pair, Public};
+use frame_support::{traits::Get, PalletId};
+use log::{debug, info, warn};
+use pallet_evm::AddressMapping;
+use pallet_transaction_payment::Multiplier;
+use parity_scale_codec::{Decode, Encode};
+use sc_service::{config::MultiaddrWithPeerId, ChainType, Properties};
+use sc_telemetry::TelemetryEndpoints;
+use serde::Deserialize;
+use sp_core::{
+    crypto::{ByteArray, Ss58Codec},
+    sr25519, Pair, H160, H256,
+};
 use sp_runtime::{
-    traits::{IdentifyAccount, Verify},
-    Permill,
+    traits::{
+        AccountIdConversion, Block as BlockT, CheckedAdd, Header as HeaderT, TrailingZeroInput,
+    },
+    FixedPointNumber, Permill, Perquintill,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+use xcm::{latest::MultiLocation, VersionedMultiLocation};
+
+use super::{
+    dapp_staking_reward_portion, dapp_staking_slot_distribution, dapp_staking_slots_per_tier,
+    get_account_id_from_seed, get_from_seed, precompile_revert_accounts, Extensions, RelayChain,
 };
 
-use super::{get_from_seed, Extensions};
+/// Para id under which Astar is registered on Polkadot.
+pub const ASTAR_PARA_ID: u32 = 2006;
 
-const PARA_ID: u32 = 2006;
+/// SS58 prefix of addresses which aren't bound to a network.
+const GENERIC_SS58_PREFIX: u16 = 42;
+
+/// Environment variable which can be used to override the dev chain sudo key.
+const SUDO_KEY_ENV: &str = "ASTAR_SUDO_SS58";
 
 /// Specialized `ChainSpec` for Astar Network.
 pub type AstarChainSpec = sc_service::GenericChainSpec<astar_runtime::GenesisConfig, Extensions>;
 
-/// Gen Astar chain specification for given parachain id.
+/// Collator entry, `(collator, session keys controller, aura)`.
+pub type CollatorKeys = (AccountId, AccountId, AuraId);
+
+/// Vesting schedule entry, `(who, begin, length, liquid)`.
+pub type VestingEntry = (AccountId, BlockNumber, BlockNumber, Balance);
+
+/// dApp registration entry, `(smart contract, owner)`.
+pub type DappEntry = (SmartContract<AccountId>, AccountId);
+
+/// dApp stake entry, `(staker, smart contract, amount)`.
+pub type StakeEntry = (AccountId, SmartContract<AccountId>, Balance);
+
+/// Gen Astar chain specification.
 pub fn get_chain_spec() -> AstarChainSpec {
-    // Alice as default
-    let sudo_key = get_account_id_from_seed::<sr25519::Public>("Alice");
-    let endowned = vec![
+    get_chain_spec_for_para_id(ASTAR_PARA_ID)
+}
+
+/// Gen Astar chain specification in its raw form, i.e. JSON with the genesis resolved to storage
+/// key/value pairs.
+pub fn get_chain_spec_raw() -> Result<String, String> {
+    get_chain_spec().as_json(true)
+}
+
+/// Gen Astar chain specification for given parachain id.
+pub fn get_chain_spec_for_para_id(para_id: u32) -> AstarChainSpec {
+    AstarChainSpecBuilder::default()
+        .genesis(
+            AstarGenesisBuilder::default()
+                .para_id(para_id)
+                .sudo(resolve_sudo_key()),
+        )
+        .build()
+}
+
+/// Gen Astar chain specification for a quick dev run with `Alice` as the single collator.
+///
+/// Only a single candidate is desired, and the candidacy bond is lowered to an amount any dev
+/// account can afford.
+pub fn get_single_collator_chain_spec() -> AstarChainSpec {
+    AstarChainSpecBuilder::default()
+        .genesis(
+            AstarGenesisBuilder::default()
+                .sudo(resolve_sudo_key())
+                .authorities(vec![authority_keys_from_seed("Alice")])
+                .collator_selection(CollatorSelectionParams {
+                    desired_candidates: 1,
+                    candidacy_bond: 1_000 * ASTR,
+                }),
+        )
+        .build()
+}
+
+/// Gen Astar `Live` chain specification, using the given boot nodes & telemetry endpoints.
+///
+/// The sudo key, the collators and the endowed accounts must be provided, the well-known dev
+/// accounts (`Alice`, `Bob`, ...) are rejected.
+pub fn get_live_chain_spec(
+    boot_nodes: Vec<MultiaddrWithPeerId>,
+    telemetry_endpoints: Option<TelemetryEndpoints>,
+    sudo_key: AccountId,
+    authorities: Vec<(AccountId, AuraId)>,
+    balances: Vec<(AccountId, Balance)>,
+) -> Result<AstarChainSpec, ChainSpecError> {
+    check_no_dev_accounts(
+        std::iter::once(&sudo_key)
+            .chain(authorities.iter().map(|(who, _)| who))
+            .chain(balances.iter().map(|(who, _)| who)),
+    )?;
+
+    AstarChainSpecBuilder::default()
+        .name("Astar")
+        .chain_type(ChainType::Live)
+        .boot_nodes(boot_nodes)
+        .telemetry_endpoints(telemetry_endpoints)
+        .genesis(
+            AstarGenesisBuilder::default()
+                .sudo(sudo_key)
+                .authorities(authorities)
+                .balances(balances),
+        )
+        .try_build()
+}
+
+/// Reject the accounts, and their stashes, derived from the well-known dev seeds.
+fn check_no_dev_accounts<'a>(
+    accounts: impl Iterator<Item = &'a AccountId>,
+) -> Result<(), ChainSpecError> {
+    let dev_accounts: Vec<_> = ["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"]
+        .into_iter()
+        .flat_map(|seed| [seed.to_string(), format!("{}//stash", seed)])
+        .map(|seed| get_account_id_from_seed::<sr25519::Public>(&seed))
+        .collect();
+
+    for who in accounts {
+        if dev_accounts.contains(who) {
+            return Err(ChainSpecError::InvalidAccount(format!(
+                "Dev account {} can't be used in a live chain specification",
+                who
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Gen Astar `Local` chain specification for multi-node testing against a relay chain.
+///
+/// `Alice`, `Bob`, `Charlie` and `Dave` are the collators, all of them as well as `Eve` and
+/// `Ferdie` are endowed.
+pub fn get_local_chain_spec() -> AstarChainSpec {
+    let collators = ["Alice", "Bob", "Charlie", "Dave"];
+    let endowed = ["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"];
+
+    AstarChainSpecBuilder::default()
+        .name("Astar Local Testnet")
+        .id("astar-local")
+        .chain_type(ChainType::Local)
+        .genesis(
+            AstarGenesisBuilder::default()
+                .balances(
+                    endowed
+                        .into_iter()
+                        .map(|seed| {
+                            (
+                                get_account_id_from_seed::<sr25519::Public>(seed),
+                                1_000_000_000 * ASTR,
+                            )
+                        })
+                        .collect(),
+                )
+                .authorities(
+                    collators
+                        .into_iter()
+                        .map(authority_keys_from_seed)
+                        .collect(),
+                ),
+        )
+        .build()
+}
+
+/// Gen Astar chain specification with `count` additional dev accounts endowed.
+///
+/// See [`dev_accounts`] for how the accounts are derived.
+pub fn get_chain_spec_with_dev_accounts(count: usize) -> AstarChainSpec {
+    let mut balances = default_endowment();
+    balances.extend(dev_accounts(count));
+
+    AstarChainSpecBuilder::default()
+        .genesis(
+            AstarGenesisBuilder::default()
+                .sudo(resolve_sudo_key())
+                .balances(balances),
+        )
+        .build()
+}
+
+/// Endowment of each account generated by [`dev_accounts`].
+pub const DEV_ACCOUNT_BALANCE: Balance = 1_000_000 * ASTR;
+
+/// Generate `count` deterministic sr25519 dev accounts, each endowed with [`DEV_ACCOUNT_BALANCE`].
+///
+/// Accounts are derived from the `//dev-0`, `//dev-1`, ... seeds.
+pub fn dev_accounts(count: usize) -> Vec<(AccountId, Balance)> {
+    (0..count)
+        .map(|index| {
+            (
+                get_account_id_from_seed::<sr25519::Public>(&format!("dev-{}", index)),
+                DEV_ACCOUNT_BALANCE,
+            )
+        })
+        .collect()
+}
+
+/// Generate `count` deterministic sr25519 key pairs, along with genesis entries endowing each
+/// of their accounts with `balance`.
+///
+/// Pairs are derived from the same `//dev-0`, `//dev-1`, ... seeds as [`dev_accounts`], so the
+/// signing keys can be recreated with `sr25519::Pair::from_string("//dev-<index>", None)`.
+pub fn funded_keypairs(
+    count: usize,
+    balance: Balance,
+) -> (Vec<(AccountId, Balance)>, Vec<sr25519::Pair>) {
+    (0..count)
+        .map(|index| {
+            let pair = sr25519::Pair::from_string(&format!("//dev-{}", index), None)
+                .expect("static values are valid; qed");
+            (
+                (AccountPublic::from(pair.public()).into_account(), balance),
+                pair,
+            )
+        })
+        .unzip()
+}
+
+/// Accounts endowed in the dev chain specification.
+fn default_endowment() -> Vec<(AccountId, Balance)> {
+    vec![
         (
             get_account_id_from_seed::<sr25519::Public>("Alice"),
             1_000_000_000 * ASTR,
@@ -51,148 +269,4780 @@ pub fn get_chain_spec() -> AstarChainSpec {
             get_account_id_from_seed::<sr25519::Public>("Bob"),
             1_000_000_000 * ASTR,
         ),
-    ];
+    ]
+}
 
-    let mut properties = serde_json::map::Map::new();
-    properties.insert("tokenSymbol".into(), "ASTR".into());
-    properties.insert("tokenDecimals".into(), 18.into());
+/// Endow `extra` accounts on top of the balances already present in `spec`'s genesis.
+///
+/// Balances of accounts which are already endowed are summed up rather than overwritten.
+/// Fails if the spec doesn't hold a (non-raw) Astar runtime genesis or a balance overflows.
+pub fn with_extra_endowed(
+    spec: AstarChainSpec,
+    extra: Vec<(AccountId, Balance)>,
+) -> Result<AstarChainSpec, String> {
+    let mut genesis = runtime_genesis(&spec)?;
+
+    for (who, amount) in extra {
+        let balances = &mut genesis.balances.balances;
+        match balances.iter_mut().find(|(account, _)| *account == who) {
+            Some((_, balance)) => {
+                *balance = balance
+                    .checked_add(amount)
+                    .ok_or_else(|| format!("Balance of {} overflows", who))?
+            }
+            None => balances.push((who, amount)),
+        }
+    }
+
+    // `GenesisConfig` isn't `Clone`, keep it serialized to hand out a fresh copy on each call.
+    let genesis = serde_json::to_string(&genesis)
+        .map_err(|e| format!("Error serializing chain spec genesis: {}", e))?;
+
+    Ok(AstarChainSpec::from_genesis(
+        spec.name(),
+        spec.id(),
+        sc_service::ChainSpec::chain_type(&spec),
+        move || serde_json::from_str(&genesis).expect("genesis was serialized above; qed"),
+        spec.boot_nodes().to_vec(),
+        spec.telemetry_endpoints().clone(),
+        spec.protocol_id(),
+        spec.fork_id(),
+        Some(spec.properties()),
+        spec.extensions().clone(),
+    ))
+}
+
+/// Wrap a pre-built runtime genesis into a chain specification, with the default Astar
+/// properties & protocol id.
+///
+/// The genesis is used as is, without any validation, giving tests full control over it.
+pub fn spec_from_genesis_config(
+    genesis: astar_runtime::GenesisConfig,
+    name: &str,
+    id: &str,
+    chain_type: ChainType,
+    extensions: Extensions,
+) -> AstarChainSpec {
+    // `GenesisConfig` isn't `Clone`, keep it serialized to hand out a fresh copy on each call.
+    let genesis = serde_json::to_string(&genesis).expect("genesis config is serializable; qed");
 
     AstarChainSpec::from_genesis(
-        "Astar Testnet",
-        "astar",
-        ChainType::Development,
-        move || make_genesis(endowned.clone(), sudo_key.clone(), PARA_ID.into()),
+        name,
+        id,
+        chain_type,
+        move || serde_json::from_str(&genesis).expect("genesis was serialized above; qed"),
         vec![],
         None,
+        Some("astar"),
         None,
-        None,
-        Some(properties),
-        Extensions {
-            bad_blocks: Default::default(),
-            relay_chain: "tokyo".into(),
-            para_id: PARA_ID,
-        },
+        Some(build_properties("ASTR", 18, SS58Prefix::get().into())),
+        extensions,
     )
 }
 
-fn session_keys(aura: AuraId) -> astar_runtime::SessionKeys {
-    astar_runtime::SessionKeys { aura }
+/// Runtime genesis of the (non-raw) chain specification.
+fn runtime_genesis(
+    spec: &dyn sc_service::ChainSpec,
+) -> Result<astar_runtime::GenesisConfig, String> {
+    let json = spec.as_json(false)?;
+    serde_json::from_str::<ChainSpecGenesis>(&json)
+        .map(|spec| spec.genesis.runtime)
+        .map_err(|e| format!("Error parsing chain spec genesis: {}", e))
 }
 
-/// Helper function to create GenesisConfig.
-fn make_genesis(
-    balances: Vec<(AccountId, Balance)>,
-    root_key: AccountId,
-    parachain_id: ParaId,
-) -> astar_runtime::GenesisConfig {
-    let authorities = vec![
+/// Accounts endowed in the genesis of the (non-raw) chain specification, sorted by descending
+/// balance, along with their total balance.
+pub fn genesis_allocation(
+    spec: &dyn sc_service::ChainSpec,
+) -> Result<(Vec<(AccountId, Balance)>, Balance), String> {
+    let mut balances = runtime_genesis(spec)?.balances.balances;
+    balances.sort_by(|(_, a), (_, b)| b.cmp(a));
+    let total = total_balance(&balances)?;
+
+    Ok((balances, total))
+}
+
+/// Collators of the (non-raw) chain specification along with the Aura keys of their sessions, in
+/// genesis order.
+pub fn spec_aura_authorities(
+    spec: &dyn sc_service::ChainSpec,
+) -> Result<Vec<(AccountId, AuraId)>, String> {
+    Ok(runtime_genesis(spec)?
+        .session
+        .keys
+        .into_iter()
+        .map(|(_, collator, keys)| (collator, keys.aura))
+        .collect())
+}
+
+/// Total issuance at genesis of the (non-raw) chain specification.
+pub fn genesis_total_issuance(spec: &dyn sc_service::ChainSpec) -> Result<Balance, String> {
+    total_balance(&runtime_genesis(spec)?.balances.balances)
+}
+
+/// Accounts endowed in the genesis of the (non-raw) chain specification, encoded as SS58
+/// addresses with the given prefix, e.g. the runtime `SS58Prefix` for Astar, in genesis order.
+pub fn genesis_accounts_ss58(
+    spec: &dyn sc_service::ChainSpec,
+    prefix: u16,
+) -> Result<Vec<(String, Balance)>, String> {
+    Ok(runtime_genesis(spec)?
+        .balances
+        .balances
+        .into_iter()
+        .map(|(who, balance)| (who.to_ss58check_with_version(prefix.into()), balance))
+        .collect())
+}
+
+fn total_balance(balances: &[(AccountId, Balance)]) -> Result<Balance, String> {
+    balances
+        .iter()
+        .try_fold(0, |total: Balance, (_, balance)| {
+            total.checked_add(*balance)
+        })
+        .ok_or_else(|| "Total genesis balance overflows".into())
+}
+
+/// Blake2-256 digest of the chain specification, stable across builds of the same spec.
+///
+/// The raw form of the spec is hashed, so the digest only depends on the genesis storage rather
+/// than on how the genesis config is written down. Object keys are sorted before hashing.
+pub fn spec_blake2_256(spec: &dyn sc_service::ChainSpec) -> Result<[u8; 32], String> {
+    let json: serde_json::Value = serde_json::from_str(&spec.as_json(true)?)
+        .map_err(|e| format!("Error parsing chain spec: {}", e))?;
+    let canonical = serde_json::to_vec(&sort_keys(json))
+        .map_err(|e| format!("Error serializing chain spec: {}", e))?;
+
+    Ok(sp_core::blake2_256(&canonical))
+}
+
+/// Genesis block of the chain specification, built with the Astar runtime state version.
+fn genesis_block(spec: &dyn sc_service::ChainSpec) -> Result<astar_runtime::Block, String> {
+    cumulus_client_cli::generate_genesis_block(spec, astar_runtime::VERSION.state_version())
+}
+
+/// Storage root of the genesis state, as required to register the parachain on the relay chain.
+pub fn genesis_state_root(spec: &dyn sc_service::ChainSpec) -> Result<H256, String> {
+    Ok(*genesis_block(spec)?.header().state_root())
+}
+
+/// SCALE encoded genesis header, i.e. the genesis head data of the parachain.
+pub fn genesis_head(spec: &dyn sc_service::ChainSpec) -> Result<Vec<u8>, String> {
+    Ok(genesis_block(spec)?.header().encode())
+}
+
+/// Runtime genesis of the (non-raw) chain specification as indented JSON, with object keys &
+/// balances sorted, so that diffs between specs are meaningful.
+///
+/// Balances are sorted by account id.
+pub fn genesis_json_pretty(spec: &dyn sc_service::ChainSpec) -> Result<String, String> {
+    let mut genesis = runtime_genesis(spec)?;
+    genesis.balances.balances.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let json = serde_json::to_value(genesis)
+        .map_err(|e| format!("Error serializing chain spec genesis: {}", e))?;
+    serde_json::to_string_pretty(&sort_keys(json))
+        .map_err(|e| format!("Error serializing chain spec genesis: {}", e))
+}
+
+/// Recursively sort object keys, independently of the map implementation used by `serde_json`.
+fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let sorted: BTreeMap<_, _> = map.into_iter().collect();
+            serde_json::Value::Object(
+                sorted
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(sort_keys).collect())
+        }
+        value => value,
+    }
+}
+
+/// Part of the chain spec JSON holding the runtime genesis.
+#[derive(Deserialize)]
+struct ChainSpecGenesis {
+    genesis: RuntimeGenesis,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeGenesis {
+    runtime: astar_runtime::GenesisConfig,
+}
+
+/// Difference of a single genesis field between two chain specifications.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldDiff {
+    /// Endowments present in only one of the specs, a changed balance shows up in both.
+    Balances {
+        /// Endowments of spec `a` missing from spec `b`.
+        only_in_a: Vec<(AccountId, Balance)>,
+        /// Endowments of spec `b` missing from spec `a`.
+        only_in_b: Vec<(AccountId, Balance)>,
+    },
+    /// Sudo key changed.
+    Sudo {
+        /// Value in spec `a`.
+        a: Option<AccountId>,
+        /// Value in spec `b`.
+        b: Option<AccountId>,
+    },
+    /// Parachain id changed.
+    ParaId {
+        /// Value in spec `a`.
+        a: u32,
+        /// Value in spec `b`.
+        b: u32,
+    },
+    /// Invulnerable collators changed.
+    Invulnerables {
+        /// Value in spec `a`.
+        a: Vec<AccountId>,
+        /// Value in spec `b`.
+        b: Vec<AccountId>,
+    },
+    /// Inflation parameters changed.
+    InflationParams {
+        /// Value in spec `a`.
+        a: InflationParameters,
+        /// Value in spec `b`.
+        b: InflationParameters,
+    },
+    /// The named dApp staking parameter changed.
+    DappStaking(&'static str),
+}
+
+/// Compare the runtime genesis of two (non-raw) chain specifications.
+///
+/// Only the fields set up by the Astar genesis builder are compared, diffs are reported in a
+/// fixed order.
+pub fn diff_genesis(a: &AstarChainSpec, b: &AstarChainSpec) -> Result<Vec<FieldDiff>, String> {
+    let a = runtime_genesis(a)?;
+    let b = runtime_genesis(b)?;
+    let mut diffs = vec![];
+
+    let only_in = |x: &[(AccountId, Balance)], y: &[(AccountId, Balance)]| -> Vec<_> {
+        x.iter()
+            .filter(|entry| !y.contains(entry))
+            .cloned()
+            .collect()
+    };
+    let only_in_a = only_in(&a.balances.balances, &b.balances.balances);
+    let only_in_b = only_in(&b.balances.balances, &a.balances.balances);
+    if !only_in_a.is_empty() || !only_in_b.is_empty() {
+        diffs.push(FieldDiff::Balances {
+            only_in_a,
+            only_in_b,
+        });
+    }
+
+    if a.sudo.key != b.sudo.key {
+        diffs.push(FieldDiff::Sudo {
+            a: a.sudo.key,
+            b: b.sudo.key,
+        });
+    }
+
+    let (para_a, para_b) = (
+        u32::from(a.parachain_info.parachain_id),
+        u32::from(b.parachain_info.parachain_id),
+    );
+    if para_a != para_b {
+        diffs.push(FieldDiff::ParaId {
+            a: para_a,
+            b: para_b,
+        });
+    }
+
+    if a.collator_selection.invulnerables != b.collator_selection.invulnerables {
+        diffs.push(FieldDiff::Invulnerables {
+            a: a.collator_selection.invulnerables,
+            b: b.collator_selection.invulnerables,
+        });
+    }
+
+    if a.inflation.params != b.inflation.params {
+        diffs.push(FieldDiff::InflationParams {
+            a: a.inflation.params,
+            b: b.inflation.params,
+        });
+    }
+
+    let (dapps_a, dapps_b) = (&a.dapp_staking, &b.dapp_staking);
+    for (field, changed) in [
         (
-            get_account_id_from_seed::<sr25519::Public>("Alice"),
-            get_from_seed::<AuraId>("Alice"),
+            "reward_portion",
+            dapps_a.reward_portion != dapps_b.reward_portion,
         ),
         (
-            get_account_id_from_seed::<sr25519::Public>("Bob"),
-            get_from_seed::<AuraId>("Bob"),
+            "slot_distribution",
+            dapps_a.slot_distribution != dapps_b.slot_distribution,
         ),
-    ];
-
-    // This is supposed the be the simplest bytecode to revert without returning any data.
-    // We will pre-deploy it under all of our precompiles to ensure they can be called from
-    // within contracts.
-    // (PUSH1 0x00 PUSH1 0x00 REVERT)
-    let revert_bytecode = vec![0x60, 0x00, 0x60, 0x00, 0xFD];
-
-    astar_runtime::GenesisConfig {
-        system: SystemConfig {
-            code: wasm_binary_unwrap().to_vec(),
-        },
-        sudo: astar_runtime::SudoConfig {
-            key: Some(root_key),
-        },
-        parachain_info: ParachainInfoConfig { parachain_id },
-        balances: astar_runtime::BalancesConfig { balances },
-        vesting: astar_runtime::VestingConfig { vesting: vec![] },
-        session: astar_runtime::SessionConfig {
-            keys: authorities
-                .iter()
-                .map(|x| (x.0.clone(), x.0.clone(), session_keys(x.1.clone())))
-                .collect::<Vec<_>>(),
-        },
-        aura: astar_runtime::AuraConfig {
-            authorities: vec![],
-        },
-        aura_ext: Default::default(),
-        collator_selection: astar_runtime::CollatorSelectionConfig {
-            desired_candidates: 32,
-            candidacy_bond: 3_200_000 * ASTR,
-            invulnerables: authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
-        },
-        evm: EVMConfig {
-            // We need _some_ code inserted at the precompile address so that
-            // the evm will actually call the address.
-            accounts: Precompiles::used_addresses()
-                .map(|addr| {
-                    (
-                        addr,
-                        fp_evm::GenesisAccount {
-                            nonce: Default::default(),
-                            balance: Default::default(),
-                            storage: Default::default(),
-                            code: revert_bytecode.clone(),
-                        },
-                    )
-                })
-                .collect(),
-        },
-        ethereum: Default::default(),
-        polkadot_xcm: Default::default(),
-        assets: Default::default(),
-        parachain_system: Default::default(),
-        transaction_payment: Default::default(),
-        dapp_staking: DappStakingConfig {
-            reward_portion: vec![
-                Permill::from_percent(40),
-                Permill::from_percent(30),
-                Permill::from_percent(20),
-                Permill::from_percent(10),
-            ],
-            slot_distribution: vec![
-                Permill::from_percent(10),
-                Permill::from_percent(20),
-                Permill::from_percent(30),
-                Permill::from_percent(40),
-            ],
-            tier_thresholds: vec![
-                TierThreshold::DynamicTvlAmount {
-                    amount: 30000 * ASTR,
-                    minimum_amount: 20000 * ASTR,
-                },
-                TierThreshold::DynamicTvlAmount {
-                    amount: 7500 * ASTR,
-                    minimum_amount: 5000 * ASTR,
-                },
-                TierThreshold::DynamicTvlAmount {
-                    amount: 20000 * ASTR,
-                    minimum_amount: 15000 * ASTR,
-                },
-                TierThreshold::FixedTvlAmount {
-                    amount: 5000 * ASTR,
-                },
-            ],
-            slots_per_tier: vec![10, 20, 30, 40],
-        },
-        inflation: InflationConfig {
-            params: InflationParameters::default(),
-        },
+        (
+            "tier_thresholds",
+            dapps_a.tier_thresholds != dapps_b.tier_thresholds,
+        ),
+        (
+            "slots_per_tier",
+            dapps_a.slots_per_tier != dapps_b.slots_per_tier,
+        ),
+    ] {
+        if changed {
+            diffs.push(FieldDiff::DappStaking(field));
+        }
+    }
+
+    Ok(diffs)
+}
+
+/// Genesis invariant violated by a chain specification, see [`validate_spec`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    /// The runtime genesis couldn't be read from the spec.
+    Genesis(String),
+    /// The para id of the spec extensions differs from the one of the genesis `parachain_info`.
+    ParaIdMismatch {
+        /// Para id of the spec extensions, `None` if they're missing.
+        extensions: Option<u32>,
+        /// Para id of the genesis.
+        genesis: u32,
+    },
+    /// The dApp staking tier configuration is invalid, see [`DappStakingParams::validate`].
+    DappStaking(String),
+    /// An invulnerable collator is endowed with less than the candidacy bond.
+    UnderfundedInvulnerable {
+        /// Invulnerable account.
+        who: AccountId,
+        /// Its endowed balance.
+        balance: Balance,
+        /// Candidacy bond of the genesis collator selection configuration.
+        candidacy_bond: Balance,
+    },
+    /// A precompile used by the runtime holds no code.
+    UnseededPrecompile(H160),
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Genesis(e) => write!(f, "{}", e),
+            Self::ParaIdMismatch {
+                extensions: Some(extensions),
+                genesis,
+            } => write!(
+                f,
+                "Para id {} of the extensions differs from the genesis para id {}",
+                extensions, genesis
+            ),
+            Self::ParaIdMismatch {
+                extensions: None,
+                genesis,
+            } => write!(
+                f,
+                "Extensions with the genesis para id {} are missing",
+                genesis
+            ),
+            Self::DappStaking(e) => write!(f, "Invalid dApp staking configuration: {}", e),
+            Self::UnderfundedInvulnerable {
+                who,
+                balance,
+                candidacy_bond,
+            } => write!(
+                f,
+                "Invulnerable {} is endowed with {}, less than the candidacy bond {}",
+                who, balance, candidacy_bond
+            ),
+            Self::UnseededPrecompile(address) => {
+                write!(f, "Precompile {:?} holds no code", address)
+            }
+        }
+    }
+}
+
+/// Check the genesis invariants of the (non-raw) chain specification before distributing it.
+///
+/// All the violations are reported rather than only the first one. Precompiles are only checked
+/// if the genesis has any EVM account, i.e. if the EVM isn't disabled.
+pub fn validate_spec(spec: &dyn sc_service::ChainSpec) -> Result<(), Vec<ValidationError>> {
+    let genesis = runtime_genesis(spec).map_err(|e| vec![ValidationError::Genesis(e)])?;
+    let mut errors = vec![];
+
+    let para_id = u32::from(genesis.parachain_info.parachain_id);
+    let extensions = Extensions::try_get(spec).map(|extensions| extensions.para_id);
+    if extensions != Some(para_id) {
+        errors.push(ValidationError::ParaIdMismatch {
+            extensions,
+            genesis: para_id,
+        });
+    }
+
+    let dapp_staking = DappStakingParams {
+        reward_portion: genesis.dapp_staking.reward_portion.clone(),
+        slot_distribution: genesis.dapp_staking.slot_distribution.clone(),
+        tier_thresholds: genesis.dapp_staking.tier_thresholds.clone(),
+        slots_per_tier: genesis.dapp_staking.slots_per_tier.clone(),
+    };
+    if let Err(e) = dapp_staking.validate() {
+        errors.push(ValidationError::DappStaking(e));
+    }
+
+    let candidacy_bond = genesis.collator_selection.candidacy_bond;
+    for who in &genesis.collator_selection.invulnerables {
+        let balance = genesis
+            .balances
+            .balances
+            .iter()
+            .find(|(account, _)| account == who)
+            .map_or(0, |(_, balance)| *balance);
+        if balance < candidacy_bond {
+            errors.push(ValidationError::UnderfundedInvulnerable {
+                who: who.clone(),
+                balance,
+                candidacy_bond,
+            });
+        }
+    }
+
+    let accounts = &genesis.evm.accounts;
+    if !accounts.is_empty() {
+        errors.extend(
+            Precompiles::used_addresses()
+                .filter(|address| accounts.get(address).map_or(true, |a| a.code.is_empty()))
+                .map(ValidationError::UnseededPrecompile),
+        );
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Sudo key of the dev chain specification.
+///
+/// Taken from the `ASTAR_SUDO_SS58` environment variable if it's set and holds a valid Astar or
+/// generic SS58 address, `Alice` is used otherwise.
+pub fn resolve_sudo_key() -> AccountId {
+    sudo_key_from_env(std::env::var(SUDO_KEY_ENV).ok())
+}
+
+/// Sudo key given the value of the `ASTAR_SUDO_SS58` environment variable, see
+/// [`resolve_sudo_key`].
+fn sudo_key_from_env(value: Option<String>) -> AccountId {
+    match value {
+        Some(address) => {
+            match decode_ss58_account(&address, Some(SS58Prefix::get().into()), true) {
+                Ok(sudo_key) => {
+                    info!("Using sudo key {} provided via {}", address, SUDO_KEY_ENV);
+                    return sudo_key;
+                }
+                Err(e) => warn!("Ignoring sudo key provided via {}: {}", SUDO_KEY_ENV, e),
+            }
+        }
+        None => info!("{} not set, using default sudo key", SUDO_KEY_ENV),
+    }
+
+    // Alice as default
+    get_account_id_from_seed::<sr25519::Public>("Alice")
+}
+
+/// Gen Astar chain specification with genesis accounts read from the given JSON file.
+///
+/// The file is expected to have the following structure:
+/// ```json
+/// {
+///   "balances": [["<ss58>", <amount>]],
+///   "sudo": "<ss58>",
+///   "vesting": [["<ss58>", <begin>, <length>, <liquid>]]
+/// }
+/// ```
+/// The `vesting` entry is optional.
+pub fn get_chain_spec_from_file(path: &Path) -> Result<AstarChainSpec, ChainSpecError> {
+    let bytes = std::fs::read(path).map_err(|e| {
+        ChainSpecError::InvalidFile(format!(
+            "Error opening genesis accounts file {:?}: {}",
+            path, e
+        ))
+    })?;
+    let (balances, sudo_key, vesting) = genesis_accounts_from_json(&bytes)?;
+
+    AstarChainSpecBuilder::default()
+        .genesis(
+            AstarGenesisBuilder::default()
+                .balances(balances)
+                .sudo(sudo_key)
+                .vesting(vesting),
+        )
+        .try_build()
+}
+
+/// Genesis accounts, as described in the JSON file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenesisAccounts {
+    balances: Vec<(String, Balance)>,
+    sudo: String,
+    #[serde(default)]
+    vesting: Vec<(String, BlockNumber, BlockNumber, Balance)>,
+}
+
+/// Decode genesis accounts from JSON bytes, converting all SS58 addresses into `AccountId`s.
+fn genesis_accounts_from_json(
+    bytes: &[u8],
+) -> Result<(Vec<(AccountId, Balance)>, AccountId, Vec<VestingEntry>), ChainSpecError> {
+    let accounts: GenesisAccounts = serde_json::from_slice(bytes).map_err(|e| {
+        ChainSpecError::InvalidFile(format!("Error parsing genesis accounts: {}", e))
+    })?;
+
+    let balances = accounts
+        .balances
+        .into_iter()
+        .map(|(who, amount)| Ok((parse_account(&who)?, amount)))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(ChainSpecError::InvalidAccount)?;
+    let sudo_key = parse_account(&accounts.sudo).map_err(ChainSpecError::InvalidAccount)?;
+    let vesting = accounts
+        .vesting
+        .into_iter()
+        .map(|(who, begin, length, liquid)| Ok((parse_account(&who)?, begin, length, liquid)))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(ChainSpecError::InvalidAccount)?;
+
+    Ok((balances, sudo_key, vesting))
+}
+
+/// Gen Astar chain specification from a TOML file describing the network & its genesis.
+///
+/// The file is expected to have the following structure, every field but the addresses & amounts
+/// being optional, omitted ones default to the development chain specification:
+/// ```toml
+/// [network]
+/// name = "Astar Testnet"
+/// id = "astar-testnet"
+/// chain_type = "Local"
+/// para_id = 2006
+/// relay_chain = "tokyo"
+///
+/// [sudo]
+/// account = "<ss58>"
+///
+/// [[balances]]
+/// account = "<ss58>"
+/// amount = "<ASTR amount, e.g. 1000.5>"
+///
+/// [dapp_staking]
+/// reward_portion = [40, 30, 20, 10]
+/// slot_distribution = [10, 20, 30, 40]
+/// slots_per_tier = [10, 20, 30, 40]
+///
+/// [[collators]]
+/// account = "<ss58>"
+/// controller = "<ss58>"
+/// aura = "<hex or ss58>"
+/// ```
+/// Amounts are strings since TOML integers can't hold balances, see [`parse_astr_amount`]. dApp
+/// staking portions & distributions are given in percent. The controller of a collator defaults
+/// to its account.
+pub fn spec_from_toml(path: &Path) -> Result<AstarChainSpec, ChainSpecError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ChainSpecError::InvalidFile(format!("Error opening spec file {:?}: {}", path, e))
+    })?;
+    spec_builder_from_toml(&content)?.try_build()
+}
+
+/// Commented template of a TOML file accepted by [`spec_from_toml`], with every field set.
+pub const SPEC_TOML_TEMPLATE: &str = include_str!("../../../res/templates/astar-spec.toml");
+
+/// Spec configuration, as described in the TOML file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlSpec {
+    #[serde(default)]
+    network: TomlNetwork,
+    sudo: Option<TomlSudo>,
+    #[serde(default)]
+    balances: Vec<TomlBalance>,
+    dapp_staking: Option<TomlDappStaking>,
+    #[serde(default)]
+    collators: Vec<TomlCollator>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlNetwork {
+    name: Option<String>,
+    id: Option<String>,
+    chain_type: Option<ChainType>,
+    para_id: Option<u32>,
+    relay_chain: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlSudo {
+    account: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlBalance {
+    account: String,
+    amount: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlDappStaking {
+    reward_portion: Option<Vec<u32>>,
+    slot_distribution: Option<Vec<u32>>,
+    slots_per_tier: Option<Vec<u16>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlCollator {
+    account: String,
+    controller: Option<String>,
+    aura: String,
+}
+
+/// Decode a spec configuration from TOML, converting all addresses & amounts.
+fn spec_builder_from_toml(content: &str) -> Result<AstarChainSpecBuilder, ChainSpecError> {
+    let spec: TomlSpec = toml::from_str(content)
+        .map_err(|e| ChainSpecError::InvalidFile(format!("Error parsing spec file: {}", e)))?;
+
+    let mut genesis = AstarGenesisBuilder::default();
+    if let Some(para_id) = spec.network.para_id {
+        genesis = genesis.para_id(para_id);
+    }
+    if let Some(sudo) = spec.sudo {
+        genesis =
+            genesis.sudo(parse_account(&sudo.account).map_err(ChainSpecError::InvalidAccount)?);
+    }
+    if !spec.balances.is_empty() {
+        let balances = spec
+            .balances
+            .into_iter()
+            .map(|TomlBalance { account, amount }| {
+                let account = parse_account(&account).map_err(ChainSpecError::InvalidAccount)?;
+                let amount = parse_astr_amount(&amount).map_err(ChainSpecError::InvalidFile)?;
+                Ok((account, amount))
+            })
+            .collect::<Result<Vec<_>, ChainSpecError>>()?;
+        genesis = genesis.balances(balances);
+    }
+    if let Some(dapp_staking) = spec.dapp_staking {
+        let percents = |percents: Vec<u32>| -> Vec<Permill> {
+            percents.into_iter().map(Permill::from_percent).collect()
+        };
+        let mut params = DappStakingParams::default();
+        if let Some(reward_portion) = dapp_staking.reward_portion {
+            params.reward_portion = percents(reward_portion);
+        }
+        if let Some(slot_distribution) = dapp_staking.slot_distribution {
+            params.slot_distribution = percents(slot_distribution);
+        }
+        if let Some(slots_per_tier) = dapp_staking.slots_per_tier {
+            params.slots_per_tier = slots_per_tier;
+        }
+        genesis = genesis.dapp_staking(params);
+    }
+    if !spec.collators.is_empty() {
+        let collators = spec
+            .collators
+            .into_iter()
+            .map(|collator| {
+                let account = parse_account(&collator.account)?;
+                let controller = match collator.controller {
+                    Some(controller) => parse_account(&controller)?,
+                    None => account.clone(),
+                };
+                Ok((account, controller, parse_aura_id(&collator.aura)?))
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(ChainSpecError::InvalidAccount)?;
+        genesis = genesis.collators(collators);
+    }
+
+    let mut builder = AstarChainSpecBuilder::default().genesis(genesis);
+    if let Some(name) = spec.network.name {
+        builder = builder.name(name);
     }
+    if let Some(id) = spec.network.id {
+        builder = builder.id(id);
+    }
+    if let Some(chain_type) = spec.network.chain_type {
+        builder = builder.chain_type(chain_type);
+    }
+    if let Some(relay_chain) = spec.network.relay_chain {
+        builder = builder.relay_chain(relay_chain);
+    }
+
+    Ok(builder)
+}
+
+/// Balances read from a JSON snapshot of another chain's accounts, rescaled to the 18 decimals
+/// of ASTR, e.g. to bootstrap a fork.
+///
+/// The snapshot is expected to have the following structure:
+/// ```json
+/// {
+///   "decimals": <decimals of the source chain>,
+///   "balances": [["<ss58>", <amount>]]
+/// }
+/// ```
+/// Addresses may use the SS58 prefix of the source chain. Amounts are rounded down when the
+/// source chain has more decimals than ASTR.
+pub fn balances_from_snapshot(path: &Path) -> Result<Vec<(AccountId, Balance)>, ChainSpecError> {
+    let bytes = std::fs::read(path).map_err(|e| {
+        ChainSpecError::InvalidFile(format!("Error reading snapshot {}: {}", path.display(), e))
+    })?;
+    snapshot_balances_from_json(&bytes)
+}
+
+/// Maximum size of a response fetched by [`balances_from_url`].
+#[cfg(feature = "remote-spec")]
+pub const REMOTE_SPEC_MAX_SIZE: usize = 16 * 1024 * 1024;
+
+/// Maximum time spent fetching a response by [`balances_from_url`].
+#[cfg(feature = "remote-spec")]
+pub const REMOTE_SPEC_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Balances read from a JSON snapshot served at the given URL, e.g. by a CI artifact store, see
+/// [`balances_from_snapshot`] for the expected structure.
+///
+/// Both `http://` & `https://` URLs are supported, and redirects are followed. The response is
+/// bounded by [`REMOTE_SPEC_MAX_SIZE`] & [`REMOTE_SPEC_TIMEOUT`].
+#[cfg(feature = "remote-spec")]
+pub fn balances_from_url(url: &str) -> Result<Vec<(AccountId, Balance)>, ChainSpecError> {
+    let bytes = http_get(url, REMOTE_SPEC_MAX_SIZE, REMOTE_SPEC_TIMEOUT)
+        .map_err(|e| ChainSpecError::InvalidFile(format!("Error fetching {}: {}", url, e)))?;
+    snapshot_balances_from_json(&bytes)
+}
+
+/// Body of a successful `GET` of the URL, at most `max_size` bytes long.
+///
+/// `timeout` bounds the whole request, from resolving the host to reading the last byte of the
+/// body.
+#[cfg(feature = "remote-spec")]
+fn http_get(url: &str, max_size: usize, timeout: std::time::Duration) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+
+    let exceeded = || format!("Response exceeds {} bytes", max_size);
+    let response = ureq::AgentBuilder::new()
+        .timeout(timeout)
+        .build()
+        .get(url)
+        .call()
+        .map_err(|e| e.to_string())?;
+    let length = response
+        .header("Content-Length")
+        .and_then(|length| length.parse::<usize>().ok());
+    if length.map_or(false, |length| length > max_size) {
+        return Err(exceeded());
+    }
+
+    let mut body = vec![];
+    response
+        .into_reader()
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| e.to_string())?;
+    if body.len() > max_size {
+        return Err(exceeded());
+    }
+    Ok(body)
+}
+
+/// Balances snapshot, as described in the JSON file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BalancesSnapshot {
+    decimals: u8,
+    balances: Vec<(String, Balance)>,
+}
+
+/// Decode a balances snapshot from JSON bytes, rescaling the amounts to ASTR decimals.
+fn snapshot_balances_from_json(bytes: &[u8]) -> Result<Vec<(AccountId, Balance)>, ChainSpecError> {
+    const DECIMALS: u8 = 18;
+
+    let snapshot: BalancesSnapshot = serde_json::from_slice(bytes).map_err(|e| {
+        ChainSpecError::InvalidFile(format!("Error parsing balances snapshot: {}", e))
+    })?;
+    let balances = snapshot
+        .balances
+        .into_iter()
+        .map(|(who, amount)| Ok((decode_ss58_account(&who, None, false)?, amount)))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(ChainSpecError::InvalidAccount)?;
+
+    rescale_balances(balances, snapshot.decimals, DECIMALS).map_err(ChainSpecError::InvalidFile)
+}
+
+/// Rescale balances expressed with `from_decimals` to `to_decimals`, e.g. from a 12 decimals
+/// chain to the 18 decimals of ASTR.
+///
+/// Scaling up is exact, an error is returned if a balance overflows. Scaling down rounds the
+/// balances down.
+pub fn rescale_balances(
+    balances: Vec<(AccountId, Balance)>,
+    from_decimals: u8,
+    to_decimals: u8,
+) -> Result<Vec<(AccountId, Balance)>, String> {
+    let factor =
+        Balance::checked_pow(10, from_decimals.abs_diff(to_decimals).into()).ok_or_else(|| {
+            format!(
+                "Unsupported rescaling from {} to {} decimals",
+                from_decimals, to_decimals
+            )
+        })?;
+
+    balances
+        .into_iter()
+        .map(|(who, amount)| {
+            let balance = if from_decimals <= to_decimals {
+                amount
+                    .checked_mul(factor)
+                    .ok_or_else(|| format!("Balance of {} overflows once rescaled", who))?
+            } else {
+                amount / factor
+            };
+            Ok((who, balance))
+        })
+        .collect()
+}
+
+/// Decode an SS58 address read from a file, expecting the Astar prefix, see [`load_account`].
+fn parse_account(address: &str) -> Result<AccountId, String> {
+    decode_ss58_account(address, Some(SS58Prefix::get().into()), false)
+}
+
+/// Decode an SS58 address loaded from an external input, e.g. a genesis accounts file.
+///
+/// An address encoded for another network than `expected_ss58_prefix`, e.g. a Kusama address,
+/// still decodes to a valid but most likely wrong account. It's reported with a warning, or
+/// rejected if `strict` is set. Addresses with the generic Substrate prefix are accepted for any
+/// network.
+pub fn load_account(
+    address: &str,
+    expected_ss58_prefix: Option<u16>,
+    strict: bool,
+) -> Result<AccountId, ChainSpecError> {
+    decode_ss58_account(address, expected_ss58_prefix, strict)
+        .map_err(ChainSpecError::InvalidAccount)
+}
+
+/// Decode a checksummed SS58 address into an `AccountId`.
+///
+/// If `prefix` is given, addresses encoded for any other network than `prefix` or the generic
+/// Substrate one are rejected, i.e. it's a strict [`load_account`].
+pub fn account_from_ss58(address: &str, prefix: Option<u16>) -> Result<AccountId, ChainSpecError> {
+    load_account(address, prefix, true)
 }
 
-type AccountPublic = <Signature as Verify>::Signer;
+/// The SS58 decoder behind [`load_account`] and [`account_from_ss58`].
+fn decode_ss58_account(
+    address: &str,
+    expected_ss58_prefix: Option<u16>,
+    strict: bool,
+) -> Result<AccountId, String> {
+    let (account, format) = AccountId::from_ss58check_with_version(address)
+        .map_err(|e| format!("Invalid SS58 address `{}`: {:?}", address, e))?;
+
+    let prefix = format.prefix();
+    match expected_ss58_prefix {
+        Some(expected) if prefix != expected && prefix != GENERIC_SS58_PREFIX => {
+            let mismatch = format!(
+                "SS58 address `{}` is encoded with prefix {} rather than {}",
+                address, prefix, expected
+            );
+            if strict {
+                return Err(format!("Invalid {}", mismatch));
+            }
+            warn!("{}", mismatch);
+        }
+        _ => (),
+    }
+
+    Ok(account)
+}
+
+/// Parse a decimal amount of ASTR, e.g. `1000.5`, into a balance.
+///
+/// At most 18 fractional digits, the precision of ASTR, are accepted.
+pub fn parse_astr_amount(amount: &str) -> Result<Balance, String> {
+    const DECIMALS: usize = 18;
+    let invalid = |reason: &str| format!("Invalid ASTR amount `{}`: {}", amount, reason);
+
+    let (integer, fraction) = match amount.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (amount, None),
+    };
+    let is_number = |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+    if !is_number(integer) || !fraction.map_or(true, is_number) {
+        return Err(invalid("not a decimal number"));
+    }
+    let fraction = fraction.unwrap_or_default();
+    if fraction.len() > DECIMALS {
+        return Err(invalid("more than 18 fractional digits"));
+    }
+
+    let overflow = || invalid("overflows the balance type");
+    let integer: Balance = integer.parse().map_err(|_| overflow())?;
+    let fraction: Balance = format!("{:0<width$}", fraction, width = DECIMALS)
+        .parse()
+        .expect("at most 18 digits fit in a balance; qed");
+    integer
+        .checked_mul(ASTR)
+        .and_then(|integer| integer.checked_add(fraction))
+        .ok_or_else(overflow)
+}
+
+/// Decode a `0x` prefixed, hex encoded 32 byte public key into an `AccountId`.
+pub fn account_from_hex(hex: &str) -> Result<AccountId, String> {
+    if !hex.starts_with("0x") {
+        return Err(format!("Invalid account `{}`: missing `0x` prefix", hex));
+    }
+    let bytes =
+        sp_core::bytes::from_hex(hex).map_err(|e| format!("Invalid account `{}`: {}", hex, e))?;
+    let bytes = <[u8; 32]>::try_from(&bytes[..]).map_err(|_| {
+        format!(
+            "Invalid account `{}`: expected 32 bytes, found {}",
+            hex,
+            bytes.len()
+        )
+    })?;
+
+    Ok(AccountId::from(bytes))
+}
+
+/// Account of the multisig between `signatories` with the given `threshold`, as derived by
+/// `pallet_multisig`.
+///
+/// Signatories are sorted & deduplicated first, so their order doesn't matter.
+pub fn multisig_account_id(signatories: &[AccountId], threshold: u16) -> AccountId {
+    let mut signatories = signatories.to_vec();
+    signatories.sort();
+    signatories.dedup();
+
+    let entropy = (b"modlpy/utilisuba", &signatories, threshold).using_encoded(sp_core::blake2_256);
+    AccountId::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+        .expect("infinite length input; no invalid inputs for type; qed")
+}
+
+/// Account derived from a pallet id, e.g. the treasury or a reward pot.
+pub fn pallet_account(id: PalletId) -> AccountId {
+    id.into_account_truncating()
+}
+
+/// Gen Astar chain specification with the invulnerable collators read from the given CSV file.
+///
+/// See [`load_collators_csv`] for the expected file format.
+pub fn get_chain_spec_from_collators_csv(path: &Path) -> Result<AstarChainSpec, ChainSpecError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ChainSpecError::InvalidFile(format!("Error opening collators file {:?}: {}", path, e))
+    })?;
+    let collators = collators_from_csv(&content).map_err(ChainSpecError::InvalidAccount)?;
+
+    AstarChainSpecBuilder::default()
+        .genesis(AstarGenesisBuilder::default().collators(collators))
+        .try_build()
+}
+
+/// Load collators from a CSV file with lines of `stash_ss58,controller_ss58,aura`.
+///
+/// `stash` is the invulnerable collator account, while `controller` is the account owning its
+/// session keys. `aura` is either the hex encoded public key or its SS58 address. Empty lines &
+/// lines starting with `#` are ignored. All malformed lines are reported, each prefixed with its
+/// line number.
+pub fn load_collators_csv(path: &Path) -> Result<Vec<CollatorKeys>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Error opening collators file {:?}: {}", path, e))?;
+
+    collators_from_csv(&content)
+}
+
+fn collators_from_csv(content: &str) -> Result<Vec<CollatorKeys>, String> {
+    let mut collators = vec![];
+    let mut errors = vec![];
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match parse_collator_line(line) {
+            Ok(collator) => collators.push(collator),
+            Err(e) => errors.push(format!("line {}: {}", index + 1, e)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(collators)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
 
-/// Helper function to generate an account ID from seed
-fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
-where
-    AccountPublic: From<<TPublic::Pair as Pair>::Public>,
-{
-    AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+fn parse_collator_line(line: &str) -> Result<CollatorKeys, String> {
+    let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+    let [stash, controller, aura] = fields[..] else {
+        return Err(format!("expected 3 fields, found {}", fields.len()));
+    };
+
+    Ok((
+        parse_account(stash)?,
+        parse_account(controller)?,
+        parse_aura_id(aura)?,
+    ))
+}
+
+/// Decode an Aura key given either as a `0x` prefixed hex encoded public key or as an SS58
+/// address.
+fn parse_aura_id(key: &str) -> Result<AuraId, String> {
+    if key.starts_with("0x") {
+        aura_id_from_hex(key)
+    } else {
+        AuraId::from_ss58check_with_version(key)
+            .map(|(aura, _)| aura)
+            .map_err(|e| format!("Invalid Aura key `{}`: {:?}", key, e))
+    }
+}
+
+/// Decode a hex encoded 32 byte Aura public key.
+pub fn aura_id_from_hex(hex: &str) -> Result<AuraId, String> {
+    let bytes =
+        sp_core::bytes::from_hex(hex).map_err(|e| format!("Invalid Aura key `{}`: {}", hex, e))?;
+    AuraId::from_slice(&bytes).map_err(|_| {
+        format!(
+            "Invalid Aura key `{}`: expected 32 bytes, found {}",
+            hex,
+            bytes.len()
+        )
+    })
+}
+
+/// SS58 address of an Aura public key, with the Astar prefix.
+pub fn aura_id_to_ss58(aura: &AuraId) -> String {
+    aura.to_ss58check_with_version(SS58Prefix::get().into())
+}
+
+/// Builder of Astar chain specifications.
+///
+/// Defaults to the development chain specification.
+///
+/// Note that the EVM chain id can't be configured, unlike Shibuya the Astar runtime has no
+/// `EVMChainId` pallet and uses the fixed `ChainId` parameter (`0x250`) instead. A forked network
+/// which must not accept replayed mainnet transactions needs a runtime with a different `ChainId`.
+pub struct AstarChainSpecBuilder {
+    name: String,
+    id: String,
+    chain_type: ChainType,
+    boot_nodes: Vec<MultiaddrWithPeerId>,
+    telemetry_endpoints: Option<TelemetryEndpoints>,
+    protocol_id: String,
+    fork_id: Option<String>,
+    relay_chain: String,
+    bad_blocks: Vec<H256>,
+    genesis: AstarGenesisBuilder,
+    properties: Properties,
+}
+
+impl Default for AstarChainSpecBuilder {
+    fn default() -> Self {
+        Self {
+            name: "Astar Testnet".into(),
+            id: "astar".into(),
+            chain_type: ChainType::Development,
+            boot_nodes: vec![],
+            telemetry_endpoints: None,
+            protocol_id: "astar".into(),
+            fork_id: None,
+            relay_chain: RelayChain::Tokyo.into(),
+            bad_blocks: vec![],
+            genesis: Default::default(),
+            properties: build_properties("ASTR", 18, SS58Prefix::get().into()),
+        }
+    }
+}
+
+impl AstarChainSpecBuilder {
+    /// Human-readable name of the chain.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Id of the chain, must start with `astar`.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Type of the chain.
+    pub fn chain_type(mut self, chain_type: ChainType) -> Self {
+        self.chain_type = chain_type;
+        self
+    }
+
+    /// Boot nodes of the chain.
+    pub fn boot_nodes(mut self, boot_nodes: Vec<MultiaddrWithPeerId>) -> Self {
+        self.boot_nodes = boot_nodes;
+        self
+    }
+
+    /// Telemetry endpoints nodes should report to.
+    pub fn telemetry_endpoints(mut self, telemetry_endpoints: Option<TelemetryEndpoints>) -> Self {
+        self.telemetry_endpoints = telemetry_endpoints;
+        self
+    }
+
+    /// Telemetry endpoint nodes should report to, given as a websocket URL & verbosity level.
+    pub fn telemetry_url(self, telemetry_url: Option<(String, u8)>) -> Result<Self, String> {
+        let telemetry_endpoints = telemetry_url
+            .map(|(url, verbosity)| telemetry_endpoints(url, verbosity))
+            .transpose()?;
+
+        Ok(self.telemetry_endpoints(telemetry_endpoints))
+    }
+
+    /// Network protocol id of the chain, `astar` by default.
+    ///
+    /// Keeps nodes from peering with unrelated chains using the default libp2p protocol id.
+    pub fn protocol_id(mut self, protocol_id: impl Into<String>) -> Self {
+        self.protocol_id = protocol_id.into();
+        self
+    }
+
+    /// Fork id, distinguishing the network from others which share its genesis, e.g. after a
+    /// consensus breaking upgrade.
+    ///
+    /// It's stored both as the `forkId` of the chain spec itself, which nodes use to tell the
+    /// networks apart, and as the [`Extensions::fork_id`].
+    pub fn fork_id(mut self, fork_id: impl Into<String>) -> Self {
+        self.fork_id = Some(fork_id.into());
+        self
+    }
+
+    /// Name of the relay chain the collator connects to, e.g. a [`RelayChain`].
+    pub fn relay_chain(mut self, relay_chain: impl Into<String>) -> Self {
+        self.relay_chain = relay_chain.into();
+        self
+    }
+
+    /// Hashes of known bad blocks, which nodes refuse to import.
+    pub fn bad_blocks(mut self, bad_blocks: Vec<H256>) -> Self {
+        self.bad_blocks = bad_blocks;
+        self
+    }
+
+    /// Runtime genesis, its parachain id is also used in the chain spec extensions.
+    pub fn genesis(mut self, genesis: AstarGenesisBuilder) -> Self {
+        self.genesis = genesis;
+        self
+    }
+
+    /// Chain properties, see [`build_properties`].
+    pub fn properties(mut self, properties: Properties) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Build the chain specification, checking its inputs first instead of panicking.
+    pub fn try_build(self) -> Result<AstarChainSpec, ChainSpecError> {
+        if astar_runtime::WASM_BINARY.is_none() {
+            return Err(ChainSpecError::MissingWasm);
+        }
+
+        let balances = &self.genesis.balances;
+        dedup_balances(balances.clone(), false)?;
+        if let Some((who, _)) = balances
+            .iter()
+            .find(|(_, balance)| *balance < ExistentialDeposit::get())
+        {
+            return Err(ChainSpecError::InvalidBalance(who.clone()));
+        }
+        self.genesis
+            .validate()
+            .map_err(|e| ChainSpecError::ValidationFailed(vec![e]))?;
+
+        Ok(self.build())
+    }
+
+    /// Build the chain specification.
+    ///
+    /// Panics right away if the genesis configuration is invalid, rather than once the genesis
+    /// storage is built, see [`Self::try_build`].
+    pub fn build(self) -> AstarChainSpec {
+        let Self {
+            name,
+            id,
+            chain_type,
+            boot_nodes,
+            telemetry_endpoints,
+            protocol_id,
+            fork_id,
+            relay_chain,
+            bad_blocks,
+            mut genesis,
+            properties,
+        } = self;
+        genesis.inflation_params = Some(resolve_inflation_params(
+            genesis.inflation_params,
+            matches!(chain_type, ChainType::Live),
+        ));
+        if let Err(e) = genesis.validate() {
+            panic!("{}", e);
+        }
+        let para_id = genesis.para_id;
+
+        AstarChainSpec::from_genesis(
+            &name,
+            &id,
+            chain_type,
+            move || genesis.clone().build(),
+            boot_nodes,
+            telemetry_endpoints,
+            Some(&protocol_id),
+            fork_id.as_deref(),
+            Some(properties),
+            Extensions {
+                bad_blocks: (!bad_blocks.is_empty()).then(|| bad_blocks.into_iter().collect()),
+                relay_chain,
+                para_id,
+                fork_id: fork_id.clone(),
+            },
+        )
+    }
+}
+
+/// Load bad block hashes from a file with one hex encoded hash per line.
+///
+/// Empty lines & lines starting with `#` are ignored.
+/// All malformed lines are reported, each prefixed with its line number.
+pub fn load_bad_blocks(path: &Path) -> Result<Vec<H256>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Error opening bad blocks file {:?}: {}", path, e))?;
+
+    bad_blocks_from_str(&content)
+}
+
+fn bad_blocks_from_str(content: &str) -> Result<Vec<H256>, String> {
+    let mut bad_blocks = vec![];
+    let mut errors = vec![];
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match parse_block_hash(line) {
+            Ok(hash) => bad_blocks.push(hash),
+            Err(e) => errors.push(format!("line {}: {}", index + 1, e)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(bad_blocks)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+fn parse_block_hash(hex: &str) -> Result<H256, String> {
+    let bytes = sp_core::bytes::from_hex(hex)
+        .map_err(|e| format!("Invalid block hash `{}`: {}", hex, e))?;
+    if bytes.len() != H256::len_bytes() {
+        return Err(format!(
+            "Invalid block hash `{}`: expected 32 bytes, found {}",
+            hex,
+            bytes.len()
+        ));
+    }
+
+    Ok(H256::from_slice(&bytes))
+}
+
+/// Load inflation parameters from a JSON file, see [`inflation_params_from_json`].
+pub fn load_inflation_params(path: &Path) -> Result<InflationParameters, String> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| format!("Error opening inflation parameters file {:?}: {}", path, e))?;
+
+    inflation_params_from_json(&bytes)
+}
+
+/// Decode inflation parameters from JSON bytes, with the same field names as
+/// [`InflationParameters`] and each `Perquintill` given as parts per quintillion.
+///
+/// Each value must be at most 100% and the parts must sum up to exactly 100%.
+pub fn inflation_params_from_json(bytes: &[u8]) -> Result<InflationParameters, String> {
+    let params: InflationParameters = serde_json::from_slice(bytes)
+        .map_err(|e| format!("Error parsing inflation parameters: {}", e))?;
+
+    for (name, value) in [
+        ("max_inflation_rate", params.max_inflation_rate),
+        ("treasury_part", params.treasury_part),
+        ("collators_part", params.collators_part),
+        ("dapps_part", params.dapps_part),
+        ("base_stakers_part", params.base_stakers_part),
+        ("adjustable_stakers_part", params.adjustable_stakers_part),
+        ("bonus_part", params.bonus_part),
+        ("ideal_staking_rate", params.ideal_staking_rate),
+    ] {
+        if value.deconstruct() > Perquintill::one().deconstruct() {
+            return Err(format!("Inflation parameter {} exceeds 100%", name));
+        }
+    }
+    if !params.is_valid() {
+        return Err("Sum of inflation parts must be exactly 100%".into());
+    }
+
+    Ok(params)
+}
+
+/// Named sets of inflation parameters, to avoid specifying every one of them by hand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InflationPreset {
+    /// Parameters of the Astar mainnet, same as [`InflationParameters::default`].
+    Mainnet,
+    /// Lower inflation, for networks which want to limit the dilution of holders.
+    Conservative,
+    /// Higher inflation & ideal staking rate, for networks which want to incentivize staking.
+    Aggressive,
+}
+
+impl InflationPreset {
+    /// Inflation parameters of the preset.
+    pub fn params(self) -> InflationParameters {
+        match self {
+            Self::Mainnet => InflationParameters::default(),
+            Self::Conservative => InflationParameters {
+                max_inflation_rate: Perquintill::from_percent(4),
+                ..Default::default()
+            },
+            Self::Aggressive => InflationParameters {
+                max_inflation_rate: Perquintill::from_percent(10),
+                ideal_staking_rate: Perquintill::from_percent(60),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Use the explicitly provided inflation parameters, or fall back to the default ones.
+///
+/// Falling back is reported for live chains, since the defaults might not match the parameters
+/// approved by governance.
+fn resolve_inflation_params(
+    inflation_params: Option<InflationParameters>,
+    is_live: bool,
+) -> InflationParameters {
+    inflation_params.unwrap_or_else(|| {
+        if is_live {
+            warn!("Using default inflation parameters for a live chain specification");
+        }
+        InflationParameters::default()
+    })
+}
+
+/// Chain properties describing the native token & the address format.
+pub fn build_properties(symbol: &str, decimals: u8, ss58_prefix: u16) -> Properties {
+    let mut properties = Properties::new();
+    properties.insert("tokenSymbol".into(), symbol.into());
+    properties.insert("tokenDecimals".into(), decimals.into());
+    properties.insert("ss58Format".into(), ss58_prefix.into());
+    properties
+}
+
+/// Telemetry endpoints consisting of the single given websocket URL.
+fn telemetry_endpoints(url: String, verbosity: u8) -> Result<TelemetryEndpoints, String> {
+    let parsed =
+        url::Url::parse(&url).map_err(|e| format!("Invalid telemetry URL `{}`: {}", url, e))?;
+    if !matches!(parsed.scheme(), "ws" | "wss") {
+        return Err(format!(
+            "Invalid telemetry URL `{}`: websocket (ws or wss) endpoint expected",
+            url
+        ));
+    }
+
+    TelemetryEndpoints::new(vec![(url.clone(), verbosity)])
+        .map_err(|e| format!("Invalid telemetry URL `{}`: {}", url, e))
+}
+
+/// Collator selection configuration used at genesis.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollatorSelectionParams {
+    /// Desired number of candidates, besides the invulnerables.
+    pub desired_candidates: u32,
+    /// Bond required to register as a candidate.
+    pub candidacy_bond: Balance,
+}
+
+impl Default for CollatorSelectionParams {
+    fn default() -> Self {
+        Self {
+            desired_candidates: 32,
+            candidacy_bond: 3_200_000 * ASTR,
+        }
+    }
+}
+
+impl CollatorSelectionParams {
+    /// `true` if an account with the given balance can afford the candidacy bond.
+    pub fn is_affordable(&self, balance: Balance) -> bool {
+        balance >= self.candidacy_bond
+    }
+}
+
+/// Candidacy bond, either absolute or derived from the genesis total issuance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CandidacyBond {
+    /// Fixed bond.
+    Absolute(Balance),
+    /// Share of the total issuance endowed at genesis.
+    PercentOfIssuance(Permill),
+}
+
+impl CandidacyBond {
+    /// Bond resulting from the given total issuance.
+    pub fn resolve(self, issuance: Balance) -> Balance {
+        match self {
+            Self::Absolute(bond) => bond,
+            Self::PercentOfIssuance(share) => share * issuance,
+        }
+    }
+}
+
+/// dApp staking tier configuration used at genesis.
+///
+/// These are all the fields of the dApp staking genesis. Other economic parameters, e.g. the
+/// minimum staking amount or the era reward span length, are constants of the runtime and can't
+/// be set at genesis.
+///
+/// dApp staking always launches out of maintenance mode, neither the maintenance flag nor the
+/// safeguard are part of its genesis. Maintenance can only be enabled afterwards, through the
+/// root `maintenance_mode` call.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DappStakingParams {
+    /// Portion of the dApp reward pool each tier receives.
+    pub reward_portion: Vec<Permill>,
+    /// Portion of the total slots each tier receives.
+    pub slot_distribution: Vec<Permill>,
+    /// Entry threshold of each tier.
+    pub tier_thresholds: Vec<TierThreshold>,
+    /// Number of slots in each tier.
+    pub slots_per_tier: Vec<u16>,
+}
+
+impl Default for DappStakingParams {
+    fn default() -> Self {
+        Self {
+            reward_portion: dapp_staking_reward_portion(),
+            slot_distribution: dapp_staking_slot_distribution(),
+            tier_thresholds: vec![
+                TierThreshold::DynamicTvlAmount {
+                    amount: 30000 * ASTR,
+                    minimum_amount: 20000 * ASTR,
+                },
+                TierThreshold::DynamicTvlAmount {
+                    amount: 7500 * ASTR,
+                    minimum_amount: 5000 * ASTR,
+                },
+                TierThreshold::DynamicTvlAmount {
+                    amount: 20000 * ASTR,
+                    minimum_amount: 15000 * ASTR,
+                },
+                TierThreshold::FixedTvlAmount {
+                    amount: 5000 * ASTR,
+                },
+            ],
+            slots_per_tier: dapp_staking_slots_per_tier(),
+        }
+    }
+}
+
+/// Maximum number of dApp staking slots, the maximum number of dApps the runtime can register:
+/// slots beyond it can never be filled.
+pub fn max_dapp_staking_slots() -> u32 {
+    <astar_runtime::Runtime as pallet_dapp_staking_v3::Config>::MaxNumberOfContracts::get()
+}
+
+impl DappStakingParams {
+    /// Check that the tier configuration is consistent.
+    ///
+    /// In case of an error, the message names the invariant which doesn't hold.
+    pub fn validate(&self) -> Result<(), String> {
+        let number_of_tiers = self.slots_per_tier.len();
+        if self.reward_portion.len() != number_of_tiers
+            || self.slot_distribution.len() != number_of_tiers
+            || self.tier_thresholds.len() != number_of_tiers
+        {
+            return Err(format!(
+                "Tier vectors must have equal length: reward_portion {}, slot_distribution {}, tier_thresholds {}, slots_per_tier {}",
+                self.reward_portion.len(),
+                self.slot_distribution.len(),
+                self.tier_thresholds.len(),
+                number_of_tiers,
+            ));
+        }
+        let expected_tiers =
+            <astar_runtime::Runtime as pallet_dapp_staking_v3::Config>::NumberOfTiers::get();
+        if number_of_tiers != expected_tiers as usize {
+            return Err(format!(
+                "The runtime has {} tiers, the configuration {}",
+                expected_tiers, number_of_tiers
+            ));
+        }
+
+        if sum_of(&self.reward_portion) != Some(Permill::one()) {
+            return Err("Sum of reward_portion must be exactly 100%".into());
+        }
+        if sum_of(&self.slot_distribution) != Some(Permill::one()) {
+            return Err("Sum of slot_distribution must be exactly 100%".into());
+        }
+
+        if let Some(tier) = self.slots_per_tier.iter().position(|slots| *slots == 0) {
+            return Err(format!("Tier {} has no slot", tier + 1));
+        }
+        if self.slots_per_tier.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err("slots_per_tier must be monotonically non-decreasing".into());
+        }
+
+        for (tier, threshold) in self.tier_thresholds.iter().enumerate() {
+            if let TierThreshold::DynamicTvlAmount {
+                amount,
+                minimum_amount,
+            } = threshold
+            {
+                if amount < minimum_amount {
+                    return Err(format!(
+                        "Tier {} threshold amount {} is below its minimum amount {}",
+                        tier + 1,
+                        amount,
+                        minimum_amount
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Total number of slots, over all the tiers.
+    pub fn total_slots(&self) -> u32 {
+        self.slots_per_tier
+            .iter()
+            .map(|slots| u32::from(*slots))
+            .sum()
+    }
+
+    /// Check that the total number of slots is within `min..=max`, see
+    /// [`AstarGenesisBuilder::dapp_staking_total_slots`].
+    ///
+    /// The total must fit in a `u16` regardless, the dApp staking genesis build panics otherwise.
+    pub fn check_total_slots(&self, min: u32, max: u32) -> Result<(), String> {
+        let total = self.total_slots();
+        if total > u16::MAX.into() {
+            return Err(format!(
+                "Total of slots_per_tier {} overflows the number of slots",
+                total
+            ));
+        }
+        if total < min || total > max {
+            return Err(format!(
+                "Total of slots_per_tier {} must be within [{}, {}]",
+                total, min, max
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check that the tier thresholds are monotonically non-increasing, i.e. that entering a
+    /// tier never requires more than entering the tiers above it.
+    ///
+    /// This isn't part of [`Self::validate`] since the default configuration doesn't satisfy it,
+    /// the third tier requiring more than the second one.
+    pub fn check_thresholds_ordering(&self) -> Result<(), String> {
+        for (tier, pair) in self.tier_thresholds.windows(2).enumerate() {
+            if pair[0].threshold() < pair[1].threshold() {
+                return Err(format!(
+                    "Tier {} threshold {} is below the tier {} threshold {}",
+                    tier + 1,
+                    pair[0].threshold(),
+                    tier + 2,
+                    pair[1].threshold()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<DappStakingParams> for DappStakingConfig {
+    fn from(params: DappStakingParams) -> Self {
+        DappStakingConfig {
+            reward_portion: params.reward_portion,
+            slot_distribution: params.slot_distribution,
+            tier_thresholds: params.tier_thresholds,
+            slots_per_tier: params.slots_per_tier,
+        }
+    }
+}
+
+/// Check that there's at least one collator to author blocks, and that invulnerable ones are
+/// endowed with at least the candidacy bond.
+fn check_collators(
+    balances: &[(AccountId, Balance)],
+    collators: &[CollatorKeys],
+    invulnerables: bool,
+    collator_selection: &CollatorSelectionParams,
+) -> Result<(), String> {
+    if collators.is_empty() {
+        return Err("No collator to author blocks".into());
+    }
+    if invulnerables {
+        check_invulnerables_funded(balances, collators, collator_selection)?;
+    }
+
+    Ok(())
+}
+
+/// Check that neither any endowment nor the total issuance exceeds its cap, if set.
+fn check_endowment_caps(
+    balances: &[(AccountId, Balance)],
+    max_per_account: Option<Balance>,
+    max_total_issuance: Option<Balance>,
+) -> Result<(), String> {
+    if let Some(max) = max_per_account {
+        if let Some((who, balance)) = balances.iter().find(|(_, balance)| *balance > max) {
+            return Err(format!(
+                "{} is endowed with {}, more than the cap {}",
+                who, balance, max
+            ));
+        }
+    }
+    if let Some(max) = max_total_issuance {
+        let issuance = total_balance(balances)?;
+        if issuance > max {
+            return Err(format!(
+                "Total issuance {} exceeds the cap {}",
+                issuance, max
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that every invulnerable collator is endowed with at least the candidacy bond.
+fn check_invulnerables_funded(
+    balances: &[(AccountId, Balance)],
+    collators: &[CollatorKeys],
+    collator_selection: &CollatorSelectionParams,
+) -> Result<(), String> {
+    for (collator, _, _) in collators {
+        let balance = balances
+            .iter()
+            .find(|(who, _)| who == collator)
+            .map_or(0, |(_, balance)| *balance);
+        if !collator_selection.is_affordable(balance) {
+            return Err(format!(
+                "Invulnerable {} is endowed with {}, less than the candidacy bond {}",
+                collator, balance, collator_selection.candidacy_bond
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Asset registered at genesis, along with its metadata.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssetGenesisEntry {
+    /// Asset id.
+    pub id: AssetId,
+    /// Owner & admin of the asset.
+    pub admin: AccountId,
+    /// Whether holding the asset is enough for an account to exist.
+    pub is_sufficient: bool,
+    /// Minimum balance of an asset account, must not be zero.
+    pub min_balance: Balance,
+    /// Asset name.
+    pub name: Vec<u8>,
+    /// Asset symbol.
+    pub symbol: Vec<u8>,
+    /// Number of decimals.
+    pub decimals: u8,
+}
+
+/// Error building an Astar chain specification, see [`AstarChainSpecBuilder::try_build`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChainSpecError {
+    /// The runtime wasm binary isn't available, e.g. its build was skipped.
+    MissingWasm,
+    /// An input file couldn't be read or decoded.
+    InvalidFile(String),
+    /// An account or its keys couldn't be decoded.
+    InvalidAccount(String),
+    /// An account is endowed with less than the existential deposit.
+    InvalidBalance(AccountId),
+    /// An account is endowed more than once.
+    DuplicateAccount(AccountId),
+    /// The genesis configuration is inconsistent, see [`AstarGenesisBuilder::validate`].
+    ValidationFailed(Vec<String>),
+}
+
+impl std::fmt::Display for ChainSpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MissingWasm => write!(f, "Astar runtime wasm binary is not available"),
+            Self::InvalidFile(e) | Self::InvalidAccount(e) => write!(f, "{}", e),
+            Self::InvalidBalance(who) => write!(
+                f,
+                "Account {} is endowed with less than the existential deposit {}",
+                who,
+                ExistentialDeposit::get()
+            ),
+            Self::DuplicateAccount(who) => write!(f, "{}", DuplicateAccount(who.clone())),
+            Self::ValidationFailed(errors) => write!(f, "{}", errors.join("\n")),
+        }
+    }
+}
+
+impl From<DuplicateAccount> for ChainSpecError {
+    fn from(DuplicateAccount(who): DuplicateAccount) -> Self {
+        Self::DuplicateAccount(who)
+    }
+}
+
+/// Account endowed more than once, see [`dedup_balances`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuplicateAccount(pub AccountId);
+
+impl std::fmt::Display for DuplicateAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Account {} is endowed more than once", self.0)
+    }
+}
+
+/// Check that no account is endowed more than once, since the balances pallet refuses to build
+/// such a genesis.
+///
+/// If `sum` is set, the balances of a duplicate account are summed up into its first entry
+/// instead, failing only if the sum overflows. The order of the accounts is kept either way.
+pub fn dedup_balances(
+    balances: Vec<(AccountId, Balance)>,
+    sum: bool,
+) -> Result<Vec<(AccountId, Balance)>, DuplicateAccount> {
+    let mut deduped: Vec<(AccountId, Balance)> = Vec::with_capacity(balances.len());
+    for (who, amount) in balances {
+        match deduped.iter_mut().find(|(account, _)| *account == who) {
+            Some((_, balance)) if sum => {
+                *balance = balance
+                    .checked_add(amount)
+                    .ok_or_else(|| DuplicateAccount(who.clone()))?
+            }
+            Some(_) => return Err(DuplicateAccount(who)),
+            None => deduped.push((who, amount)),
+        }
+    }
+
+    Ok(deduped)
+}
+
+/// Check that asset ids are unique and each asset has a non-zero minimum balance.
+fn check_assets(assets: &[AssetGenesisEntry]) -> Result<(), String> {
+    let mut ids = BTreeSet::new();
+    for asset in assets {
+        if !ids.insert(asset.id) {
+            return Err(format!("Duplicate asset id {}", asset.id));
+        }
+        if asset.min_balance == 0 {
+            return Err(format!("Asset {} has a zero minimum balance", asset.id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that every predeployed EVM account holds code, which requires the EVM to be enabled.
+fn check_evm_accounts(
+    evm_accounts: &[(H160, fp_evm::GenesisAccount)],
+    enable_evm: bool,
+) -> Result<(), String> {
+    if !enable_evm && !evm_accounts.is_empty() {
+        return Err("EVM accounts are set while the EVM is disabled".into());
+    }
+    for (address, account) in evm_accounts {
+        if account.code.is_empty() {
+            return Err(format!("EVM account {:?} has no code", address));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the initial fee multiplier, if any, is positive.
+fn check_fee_multiplier(multiplier: Option<Multiplier>) -> Result<(), String> {
+    match multiplier {
+        Some(multiplier) if !multiplier.is_positive() => {
+            Err(format!("Fee multiplier {} isn't positive", multiplier))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that every vesting account is endowed with at least its liquid amount.
+fn check_vesting_endowed(
+    balances: &[(AccountId, Balance)],
+    vesting: &[VestingEntry],
+) -> Result<(), String> {
+    for (who, _, _, liquid) in vesting {
+        match balances.iter().find(|(account, _)| account == who) {
+            None => return Err(format!("Vesting account {} isn't endowed", who)),
+            Some((_, balance)) if balance < liquid => {
+                return Err(format!(
+                    "Vesting account {} is endowed with {}, less than its liquid amount {}",
+                    who, balance, liquid
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Sum of all the `Permill` values, `None` in case of an overflow.
+fn sum_of(values: &[Permill]) -> Option<Permill> {
+    values
+        .iter()
+        .fold(Some(Permill::zero()), |acc, value| match acc {
+            Some(acc) => acc.checked_add(value),
+            None => None,
+        })
+}
+
+/// Session keys of a collator, mirroring the runtime `SessionKeys` which only holds an Aura key.
+///
+/// Should the runtime get another session key, e.g. for a second consensus mechanism, it needs to
+/// be added here as well: the conversion into the runtime keys won't compile until it is.
+///
+/// ```
+/// use astar_collator::parachain::chain_spec::astar::CollatorSessionKeys;
+/// use sp_core::crypto::ByteArray;
+///
+/// let aura = astar_runtime::AuraId::from_slice(&[1; 32]).unwrap();
+/// let keys: astar_runtime::SessionKeys = CollatorSessionKeys { aura: aura.clone() }.into();
+/// assert_eq!(keys.aura, aura);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollatorSessionKeys {
+    /// Aura key, authoring blocks.
+    pub aura: AuraId,
+}
+
+impl From<CollatorSessionKeys> for astar_runtime::SessionKeys {
+    fn from(keys: CollatorSessionKeys) -> Self {
+        // Both structs are built exhaustively, so that a key missing from either fails to compile.
+        let CollatorSessionKeys { aura } = keys;
+        Self { aura }
+    }
+}
+
+fn session_keys(keys: CollatorSessionKeys) -> astar_runtime::SessionKeys {
+    keys.into()
+}
+
+/// Substrate account mapped to the given EVM address by the runtime, holding its balance.
+pub fn evm_mapped_account(address: H160) -> AccountId {
+    <astar_runtime::Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+}
+
+/// Builder of the Astar runtime genesis.
+///
+/// Defaults to the development genesis: `Alice` & `Bob` are endowed and act as collators, `Alice`
+/// is the sudo key.
+///
+/// Note that there's no governance membership to seed, the Astar runtime has no council or
+/// technical committee pallets and is governed through sudo.
+///
+/// The chain always starts at block 0, none of the genesis configurations holds the current
+/// block number. Migration tests needing an already running chain should rather use
+/// `try-runtime` against a snapshot of a live network.
+///
+/// Nor can the chain launch paused, the Astar runtime has neither a safe-mode nor a transaction
+/// pause pallet. dApp staking has a maintenance mode of its own, but it isn't part of the dApp
+/// staking genesis either, it's enabled after launch through the root `maintenance_mode` call,
+/// see [`DappStakingParams`].
+#[derive(Clone)]
+pub struct AstarGenesisBuilder {
+    balances: Vec<(AccountId, Balance)>,
+    max_per_account: Option<Balance>,
+    max_total_issuance: Option<Balance>,
+    sudo_key: Option<AccountId>,
+    para_id: u32,
+    collators: Vec<CollatorKeys>,
+    invulnerables: bool,
+    collator_selection: CollatorSelectionParams,
+    candidacy_bond: Option<CandidacyBond>,
+    vesting: Vec<VestingEntry>,
+    inflation_params: Option<InflationParameters>,
+    dapp_staking: DappStakingParams,
+    dapp_staking_total_slots: (u32, u32),
+    assets: Vec<AssetGenesisEntry>,
+    xcm_version: Option<u32>,
+    tx_fee_multiplier: Option<Multiplier>,
+    precompile_addresses: Option<Vec<H160>>,
+    evm_accounts: Vec<(H160, fp_evm::GenesisAccount)>,
+    enable_evm: bool,
+}
+
+impl Default for AstarGenesisBuilder {
+    fn default() -> Self {
+        Self {
+            balances: default_endowment(),
+            max_per_account: None,
+            max_total_issuance: None,
+            // Alice as default
+            sudo_key: Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+            para_id: ASTAR_PARA_ID,
+            collators: vec![
+                collator_keys(authority_keys_from_seed("Alice")),
+                collator_keys(authority_keys_from_seed("Bob")),
+            ],
+            invulnerables: true,
+            collator_selection: Default::default(),
+            candidacy_bond: None,
+            vesting: vec![],
+            inflation_params: None,
+            dapp_staking: Default::default(),
+            dapp_staking_total_slots: (1, max_dapp_staking_slots()),
+            assets: vec![],
+            xcm_version: None,
+            tx_fee_multiplier: None,
+            precompile_addresses: None,
+            evm_accounts: vec![],
+            enable_evm: true,
+        }
+    }
+}
+
+impl AstarGenesisBuilder {
+    /// Endowed accounts, each must be listed only once, see [`dedup_balances`].
+    pub fn balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+        self.balances = balances;
+        self
+    }
+
+    /// Endow faucet accounts derived from the given dev seeds with `amount` each, in addition to
+    /// the accounts already endowed.
+    pub fn faucets(mut self, seeds: &[&str], amount: Balance) -> Self {
+        self.balances.extend(
+            seeds
+                .iter()
+                .map(|seed| (get_account_id_from_seed::<sr25519::Public>(seed), amount)),
+        );
+        self
+    }
+
+    /// Endow the Substrate accounts mapped to the given EVM addresses, see [`evm_mapped_account`],
+    /// in addition to the accounts already endowed.
+    ///
+    /// This funds the EVM accounts expected by EVM tests, as EVM balances are the balances of the
+    /// mapped accounts.
+    pub fn evm_funded_accounts(mut self, evm_funded_accounts: Vec<(H160, Balance)>) -> Self {
+        self.balances.extend(
+            evm_funded_accounts
+                .into_iter()
+                .map(|(address, balance)| (evm_mapped_account(address), balance)),
+        );
+        self
+    }
+
+    /// Endow the treasury account with `endowment`, so the network launches with a seeded
+    /// treasury.
+    ///
+    /// The treasury account mustn't be endowed otherwise.
+    pub fn treasury_endowment(mut self, endowment: Balance) -> Self {
+        self.balances
+            .push((pallet_account(TreasuryPalletId::get()), endowment));
+        self
+    }
+
+    /// Maximum balance a single account can be endowed with, as a safety net against mistyped
+    /// allocations.
+    pub fn max_per_account(mut self, max_per_account: Balance) -> Self {
+        self.max_per_account = Some(max_per_account);
+        self
+    }
+
+    /// Maximum total issuance at genesis, as a safety net against mistyped allocations.
+    pub fn max_total_issuance(mut self, max_total_issuance: Balance) -> Self {
+        self.max_total_issuance = Some(max_total_issuance);
+        self
+    }
+
+    /// Sudo key.
+    pub fn sudo(mut self, sudo_key: AccountId) -> Self {
+        self.sudo_key = Some(sudo_key);
+        self
+    }
+
+    /// Sudo key set to the multisig between `signatories` with the given `threshold`, see
+    /// [`multisig_account_id`].
+    pub fn sudo_multisig(self, signatories: &[AccountId], threshold: u16) -> Self {
+        self.sudo(multisig_account_id(signatories, threshold))
+    }
+
+    /// Don't set any sudo key, leaving the chain to be governed without one from genesis.
+    pub fn without_sudo(mut self) -> Self {
+        self.sudo_key = None;
+        self
+    }
+
+    /// Parachain id.
+    ///
+    /// It's the single source of the para id: [`AstarChainSpecBuilder`] derives the one of the
+    /// spec extensions from it, so that both always match, see [`validate_spec`].
+    pub fn para_id(mut self, para_id: u32) -> Self {
+        self.para_id = para_id;
+        self
+    }
+
+    /// Invulnerable collators, with their Aura keys.
+    ///
+    /// Each collator account also acts as the controller of its own session keys.
+    pub fn authorities(self, authorities: Vec<(AccountId, AuraId)>) -> Self {
+        self.collators(authorities.into_iter().map(collator_keys).collect())
+    }
+
+    /// Invulnerable collators, with the controller accounts & Aura keys of their sessions.
+    ///
+    /// Every invulnerable must be endowed with at least the candidacy bond.
+    pub fn collators(mut self, collators: Vec<CollatorKeys>) -> Self {
+        self.collators = collators;
+        self
+    }
+
+    /// Whether the collators are invulnerable, `true` by default.
+    ///
+    /// Otherwise candidacy is open from genesis: the collators only author the first sessions
+    /// through their session keys, until candidates bonded through collator selection take over.
+    pub fn invulnerables(mut self, invulnerables: bool) -> Self {
+        self.invulnerables = invulnerables;
+        self
+    }
+
+    /// Collator selection configuration.
+    pub fn collator_selection(mut self, collator_selection: CollatorSelectionParams) -> Self {
+        self.collator_selection = collator_selection;
+        self
+    }
+
+    /// Candidacy bond, overriding the one of the [`collator_selection`](Self::collator_selection)
+    /// configuration.
+    ///
+    /// A bond relative to the issuance is computed from all the endowed balances.
+    pub fn candidacy_bond(mut self, candidacy_bond: CandidacyBond) -> Self {
+        self.candidacy_bond = Some(candidacy_bond);
+        self
+    }
+
+    /// Vesting schedules, each vesting account must also be endowed.
+    pub fn vesting(mut self, vesting: Vec<VestingEntry>) -> Self {
+        self.vesting = vesting;
+        self
+    }
+
+    /// Sudo key set to the account of `vesting`, endowed with `endowment` which unlocks over the
+    /// given schedule, e.g. for a root account holding a treasury-like balance.
+    ///
+    /// The sudo account mustn't be endowed otherwise, and the endowment must cover the liquid
+    /// amount of the schedule.
+    pub fn vested_sudo(mut self, vesting: VestingEntry, endowment: Balance) -> Self {
+        self.balances.push((vesting.0.clone(), endowment));
+        self.sudo_key = Some(vesting.0.clone());
+        self.vesting.push(vesting);
+        self
+    }
+
+    /// Inflation parameters.
+    ///
+    /// Live chain specifications should always set them explicitly,
+    /// [`InflationParameters::default`] is used otherwise.
+    pub fn inflation_params(mut self, inflation_params: InflationParameters) -> Self {
+        self.inflation_params = Some(inflation_params);
+        self
+    }
+
+    /// Inflation parameters of the given preset, see [`InflationPreset`].
+    pub fn inflation_preset(self, preset: InflationPreset) -> Self {
+        self.inflation_params(preset.params())
+    }
+
+    /// dApp staking tier configuration.
+    pub fn dapp_staking(mut self, dapp_staking: DappStakingParams) -> Self {
+        self.dapp_staking = dapp_staking;
+        self
+    }
+
+    /// Bounds of the total number of dApp staking slots, over all the tiers.
+    ///
+    /// Defaults to `1..=`[`max_dapp_staking_slots`].
+    pub fn dapp_staking_total_slots(mut self, min: u32, max: u32) -> Self {
+        self.dapp_staking_total_slots = (min, max);
+        self
+    }
+
+    /// Assets registered at genesis.
+    pub fn assets(mut self, assets: Vec<AssetGenesisEntry>) -> Self {
+        self.assets = assets;
+        self
+    }
+
+    /// Initial transaction fee multiplier, e.g. to launch with elevated fees.
+    ///
+    /// It must be positive, the pallet default of `1` is used if it isn't set.
+    pub fn tx_fee_multiplier(mut self, tx_fee_multiplier: Multiplier) -> Self {
+        self.tx_fee_multiplier = Some(tx_fee_multiplier);
+        self
+    }
+
+    /// Safe XCM version, so XCM can be used right after launch without a sudo call.
+    pub fn xcm_version(mut self, xcm_version: u32) -> Self {
+        self.xcm_version = Some(xcm_version);
+        self
+    }
+
+    /// Precompile addresses to seed with the revert bytecode.
+    ///
+    /// All the precompiles used by the runtime are seeded by default.
+    pub fn precompile_addresses(mut self, addresses: Vec<H160>) -> Self {
+        self.precompile_addresses = Some(addresses);
+        self
+    }
+
+    /// EVM accounts, e.g. contracts, predeployed at genesis.
+    ///
+    /// They're added after the precompiles are seeded, so they take precedence on an address
+    /// collision, as do later entries over earlier ones.
+    pub fn evm_accounts(mut self, evm_accounts: Vec<(H160, fp_evm::GenesisAccount)>) -> Self {
+        self.evm_accounts = evm_accounts;
+        self
+    }
+
+    /// Whether the EVM is used, `true` by default.
+    ///
+    /// If disabled, no EVM accounts are created at genesis so none of the precompiles is seeded.
+    pub fn enable_evm(mut self, enable_evm: bool) -> Self {
+        self.enable_evm = enable_evm;
+        self
+    }
+
+    /// Calls registering `dapps` & placing `stakes` on them, for dApp staking integration tests.
+    ///
+    /// dApp staking has no genesis support for either, so they must be set up through extrinsics
+    /// right after launch. Stakers must be endowed with at least the sum of their stakes, and
+    /// can only stake on registered dApps.
+    pub fn dapp_staking_setup_calls(
+        &self,
+        dapps: Vec<DappEntry>,
+        stakes: Vec<StakeEntry>,
+    ) -> Result<DappStakingSetup, String> {
+        let mut staked = BTreeMap::<AccountId, Balance>::new();
+        for (staker, smart_contract, amount) in &stakes {
+            if !dapps.iter().any(|(dapp, _)| dapp == smart_contract) {
+                return Err(format!("Staked dApp {:?} isn't registered", smart_contract));
+            }
+            let total = staked.entry(staker.clone()).or_default();
+            *total = total
+                .checked_add(*amount)
+                .ok_or_else(|| format!("Stakes of {} overflow", staker))?;
+        }
+        for (staker, total) in &staked {
+            let balance = self
+                .balances
+                .iter()
+                .find(|(who, _)| who == staker)
+                .map_or(0, |(_, balance)| *balance);
+            if *total > balance {
+                return Err(format!(
+                    "Staker {} is endowed with {}, less than its stakes {}",
+                    staker, balance, total
+                ));
+            }
+        }
+
+        Ok(DappStakingSetup {
+            root: dapps
+                .into_iter()
+                .map(|(smart_contract, owner)| {
+                    RuntimeCall::DappStaking(pallet_dapp_staking_v3::Call::register {
+                        owner,
+                        smart_contract,
+                    })
+                })
+                .collect(),
+            signed: stakes
+                .into_iter()
+                .flat_map(|(staker, smart_contract, amount)| {
+                    [
+                        (
+                            staker.clone(),
+                            RuntimeCall::DappStaking(pallet_dapp_staking_v3::Call::lock { amount }),
+                        ),
+                        (
+                            staker,
+                            RuntimeCall::DappStaking(pallet_dapp_staking_v3::Call::stake {
+                                smart_contract,
+                                amount,
+                            }),
+                        ),
+                    ]
+                })
+                .collect(),
+        })
+    }
+
+    /// Calls mapping `foreign_assets` to their XCM locations, for cross-chain integration tests.
+    ///
+    /// The XC asset config pallet has no genesis support, so the mapping must be registered
+    /// through root extrinsics right after launch. Each asset must be registered in the genesis
+    /// [`assets`](Self::assets), and its location convertible to the latest XCM version.
+    pub fn foreign_asset_setup_calls(
+        &self,
+        foreign_assets: Vec<(AssetId, VersionedMultiLocation)>,
+    ) -> Result<Vec<RuntimeCall>, String> {
+        let mut ids = BTreeSet::new();
+        let mut locations = vec![];
+        for (id, location) in &foreign_assets {
+            if !self.assets.iter().any(|asset| asset.id == *id) {
+                return Err(format!("Foreign asset {} isn't registered", id));
+            }
+            if !ids.insert(*id) {
+                return Err(format!("Duplicate foreign asset id {}", id));
+            }
+            let location = MultiLocation::try_from(location.clone())
+                .map_err(|_| format!("Foreign asset {} has an unsupported location", id))?;
+            if locations.contains(&location) {
+                return Err(format!("Duplicate foreign asset location {:?}", location));
+            }
+            locations.push(location);
+        }
+
+        Ok(foreign_assets
+            .into_iter()
+            .map(|(asset_id, location)| {
+                RuntimeCall::XcAssetConfig(pallet_xc_asset_config::Call::register_asset_location {
+                    asset_location: Box::new(location),
+                    asset_id,
+                })
+            })
+            .collect())
+    }
+
+    /// Check that the genesis configuration is consistent.
+    ///
+    /// In case of an error, the message names the part of the genesis which is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        dedup_balances(self.balances.clone(), false)
+            .map_err(|e| format!("Invalid balances genesis configuration: {}", e))?;
+        check_endowment_caps(
+            &self.balances,
+            self.max_per_account,
+            self.max_total_issuance,
+        )
+        .map_err(|e| format!("Invalid balances genesis configuration: {}", e))?;
+        let (min_slots, max_slots) = self.dapp_staking_total_slots;
+        self.dapp_staking
+            .validate()
+            .and_then(|()| self.dapp_staking.check_total_slots(min_slots, max_slots))
+            .map_err(|e| format!("Invalid dApp staking genesis configuration: {}", e))?;
+        self.resolved_collator_selection()
+            .and_then(|collator_selection| {
+                check_collators(
+                    &self.balances,
+                    &self.collators,
+                    self.invulnerables,
+                    &collator_selection,
+                )
+            })
+            .map_err(|e| format!("Invalid collator selection genesis configuration: {}", e))?;
+        check_vesting_endowed(&self.balances, &self.vesting)
+            .map_err(|e| format!("Invalid vesting genesis configuration: {}", e))?;
+        check_assets(&self.assets)
+            .map_err(|e| format!("Invalid assets genesis configuration: {}", e))?;
+        check_evm_accounts(&self.evm_accounts, self.enable_evm)
+            .map_err(|e| format!("Invalid EVM genesis configuration: {}", e))?;
+        check_fee_multiplier(self.tx_fee_multiplier)
+            .map_err(|e| format!("Invalid transaction payment genesis configuration: {}", e))
+    }
+
+    /// Build the genesis config.
+    ///
+    /// Panics if the configuration is invalid, see [`Self::validate`].
+    pub fn build(self) -> astar_runtime::GenesisConfig {
+        if let Err(e) = self.validate() {
+            panic!("{}", e);
+        }
+
+        // `SystemConfig` owns the code, so a copy of the (static) blob is needed for each
+        // genesis regardless, caching a `Vec` would only trade `to_vec` for `clone`. Measured
+        // over 40 builds of a 4 MiB blob: 40 allocations (160 MiB) with `to_vec`, 41 (164 MiB)
+        // with a cached `Vec`, 40 (160 MiB) copying from a shared `Arc<[u8]>`.
+        self.build_with_code(wasm_binary_unwrap().to_vec())
+    }
+
+    /// Validate the configuration & build the genesis config without the runtime code, for fast
+    /// validation runs which don't need to copy the wasm blob.
+    pub fn dry_run(self) -> Result<GenesisReport, String> {
+        self.validate()?;
+
+        let dapp_staking = self.dapp_staking.clone();
+        let genesis = self.build_with_code(vec![]);
+        Ok(GenesisReport {
+            endowed_accounts: genesis.balances.balances.len(),
+            issuance: total_balance(&genesis.balances.balances)?,
+            invulnerables: genesis.collator_selection.invulnerables.len(),
+            dapp_staking,
+        })
+    }
+
+    /// Build the genesis config with an empty runtime code, for tests which only check the other
+    /// genesis fields.
+    ///
+    /// Panics if the configuration is invalid, see [`Self::validate`].
+    #[cfg(test)]
+    pub fn build_light(self) -> astar_runtime::GenesisConfig {
+        if let Err(e) = self.validate() {
+            panic!("{}", e);
+        }
+
+        self.build_with_code(vec![])
+    }
+
+    /// Collator selection configuration with the candidacy bond resolved, which mustn't be zero.
+    fn resolved_collator_selection(&self) -> Result<CollatorSelectionParams, String> {
+        let mut collator_selection = self.collator_selection.clone();
+        if let Some(candidacy_bond) = self.candidacy_bond {
+            collator_selection.candidacy_bond =
+                candidacy_bond.resolve(total_balance(&self.balances)?);
+            if collator_selection.candidacy_bond == 0 {
+                return Err("Candidacy bond is zero".into());
+            }
+        }
+        Ok(collator_selection)
+    }
+
+    fn build_with_code(self, code: Vec<u8>) -> astar_runtime::GenesisConfig {
+        let Self {
+            balances,
+            max_per_account: _,
+            max_total_issuance: _,
+            sudo_key,
+            para_id,
+            collators,
+            invulnerables,
+            mut collator_selection,
+            candidacy_bond,
+            vesting,
+            inflation_params,
+            dapp_staking,
+            dapp_staking_total_slots: _,
+            assets,
+            xcm_version,
+            tx_fee_multiplier,
+            precompile_addresses,
+            evm_accounts: predeployed,
+            enable_evm,
+        } = self;
+
+        let issuance = balances.iter().fold(0, |total: Balance, (_, balance)| {
+            total.saturating_add(*balance)
+        });
+        if let Some(candidacy_bond) = candidacy_bond {
+            collator_selection.candidacy_bond = candidacy_bond.resolve(issuance);
+        }
+        debug!("Building genesis for para id {}", para_id);
+        debug!(
+            "Genesis endows {} accounts, total issuance {}",
+            balances.len(),
+            issuance
+        );
+        debug!(
+            "Genesis has {} collators, {} of them invulnerable",
+            collators.len(),
+            if invulnerables { collators.len() } else { 0 }
+        );
+        debug!(
+            "Genesis sudo key is {}",
+            if sudo_key.is_some() { "set" } else { "not set" }
+        );
+
+        let mut evm_accounts = match (enable_evm, precompile_addresses) {
+            (false, _) => Default::default(),
+            (true, Some(addresses)) => precompile_revert_accounts(addresses.into_iter()),
+            (true, None) => precompile_revert_accounts(seeded_precompile_addresses().into_iter()),
+        };
+        evm_accounts.extend(predeployed);
+
+        make_genesis!(
+            astar_runtime,
+            code: code,
+            root_key: sudo_key,
+            parachain_id: para_id.into(),
+            balances: balances,
+            vesting: vesting,
+            collators: collators,
+            session_keys: |aura| session_keys(CollatorSessionKeys { aura }),
+            invulnerables: invulnerables,
+            desired_candidates: collator_selection.desired_candidates,
+            candidacy_bond: collator_selection.candidacy_bond,
+            evm_accounts: evm_accounts,
+            dapp_staking: dapp_staking.into(),
+            inflation_params: inflation_params.unwrap_or_default(),
+            polkadot_xcm: astar_runtime::PolkadotXcmConfig {
+                safe_xcm_version: xcm_version,
+            },
+            assets: astar_runtime::AssetsConfig {
+                assets: assets
+                    .iter()
+                    .map(|asset| {
+                        (
+                            asset.id,
+                            asset.admin.clone(),
+                            asset.is_sufficient,
+                            asset.min_balance,
+                        )
+                    })
+                    .collect(),
+                metadata: assets
+                    .into_iter()
+                    .map(|asset| (asset.id, asset.name, asset.symbol, asset.decimals))
+                    .collect(),
+                accounts: vec![],
+            },
+            transaction_payment: tx_fee_multiplier
+                .map(|multiplier| astar_runtime::TransactionPaymentConfig { multiplier })
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// Summary of a genesis configuration, see [`AstarGenesisBuilder::dry_run`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenesisReport {
+    /// Number of endowed accounts.
+    pub endowed_accounts: usize,
+    /// Total issuance.
+    pub issuance: Balance,
+    /// Number of invulnerable collators.
+    pub invulnerables: usize,
+    /// dApp staking tier configuration.
+    pub dapp_staking: DappStakingParams,
+}
+
+/// Calls setting up dApp staking after launch, see
+/// [`AstarGenesisBuilder::dapp_staking_setup_calls`].
+#[derive(Debug, PartialEq)]
+pub struct DappStakingSetup {
+    /// Calls to dispatch with the root origin first, e.g. through `sudo`.
+    pub root: Vec<RuntimeCall>,
+    /// Calls to submit afterwards, each signed by its account.
+    pub signed: Vec<(AccountId, RuntimeCall)>,
+}
+
+/// Precompile addresses seeded with [`super::EVM_REVERT_BYTECODE`] by default, i.e. all the
+/// precompiles used by the runtime, in ascending order.
+pub fn seeded_precompile_addresses() -> Vec<H160> {
+    let mut addresses = Precompiles::used_addresses().collect::<Vec<_>>();
+    addresses.sort();
+    addresses
+}
+
+/// Collator entry acting as the controller of its own session keys.
+fn collator_keys((account, aura): (AccountId, AuraId)) -> CollatorKeys {
+    (account.clone(), account, aura)
+}
+
+/// Generate collator account & Aura authority key from seed.
+pub fn authority_keys_from_seed(seed: &str) -> (AccountId, AuraId) {
+    (
+        get_account_id_from_seed::<sr25519::Public>(seed),
+        get_from_seed::<AuraId>(seed),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{assert_matches_genesis_fixture, EVM_REVERT_BYTECODE};
+    use super::*;
+    use sc_service::ChainSpec;
+    use sp_core::crypto::PublicError;
+    use sp_runtime::BuildStorage;
+    use std::sync::Mutex;
+
+    /// Messages logged from all the tests, at any level.
+    static LOGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct CaptureLogger;
+
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            LOGS.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    fn capture_logs() {
+        static LOGGER: CaptureLogger = CaptureLogger;
+        // Already set if another test captures logs too.
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Debug);
+    }
+
+    const GENESIS_ACCOUNTS_FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/res/fixtures/astar-genesis-accounts.json"
+    );
+    const BALANCES_SNAPSHOT_FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/res/fixtures/balances-snapshot.json"
+    );
+    const TOML_SPEC_FIXTURE: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/res/fixtures/astar-spec.toml");
+    const COLLATORS_FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/res/fixtures/astar-collators.csv"
+    );
+
+    #[test]
+    fn default_spec_uses_astar_para_id() {
+        let spec = AstarChainSpecBuilder::default().build();
+        assert_eq!(spec.extensions().para_id, ASTAR_PARA_ID);
+        assert_eq!(
+            dev_genesis().parachain_info.parachain_id,
+            ASTAR_PARA_ID.into()
+        );
+    }
+
+    #[test]
+    fn chain_spec_for_para_id_sets_extensions() {
+        assert_eq!(get_chain_spec().extensions().para_id, ASTAR_PARA_ID);
+
+        let spec = get_chain_spec_for_para_id(3000);
+        assert_eq!(spec.extensions().para_id, 3000);
+        assert_eq!(spec.extensions().relay_chain, "tokyo");
+    }
+
+    #[test]
+    fn fork_id_is_serialized_only_when_set() {
+        let json = |spec: AstarChainSpec| -> serde_json::Value {
+            serde_json::from_str(&spec.as_json(false).unwrap()).unwrap()
+        };
+
+        let spec = AstarChainSpecBuilder::default().build();
+        assert_eq!(spec.fork_id(), None);
+        assert_eq!(spec.extensions().fork_id, None);
+        let without = json(spec);
+        assert!(without.get("forkId").is_none());
+        assert!(without.get("hardForkId").is_none());
+
+        let spec = AstarChainSpecBuilder::default()
+            .fork_id("astar-fork")
+            .build();
+        assert_eq!(spec.fork_id(), Some("astar-fork"));
+        assert_eq!(spec.extensions().fork_id.as_deref(), Some("astar-fork"));
+        let loaded =
+            AstarChainSpec::from_json_bytes(spec.as_json(false).unwrap().into_bytes()).unwrap();
+        assert_eq!(loaded.extensions(), spec.extensions());
+        let with = json(spec);
+        assert_eq!(with["forkId"], "astar-fork");
+        assert_eq!(with["hardForkId"], "astar-fork");
+    }
+
+    #[test]
+    fn relay_chain_is_set() {
+        let spec = AstarChainSpecBuilder::default()
+            .relay_chain(RelayChain::Custom("rococo-local".into()))
+            .build();
+        assert_eq!(spec.extensions().relay_chain, "rococo-local");
+        assert_eq!(
+            Extensions::try_get(&spec).map(|e| e.relay_chain.as_str()),
+            Some("rococo-local")
+        );
+    }
+
+    /// Genesis built from the same inputs as `get_chain_spec`.
+    fn dev_genesis() -> astar_runtime::GenesisConfig {
+        AstarGenesisBuilder::default().build()
+    }
+
+    #[test]
+    fn light_genesis_only_lacks_the_code() {
+        let light = AstarGenesisBuilder::default().build_light();
+        assert!(light.system.code.is_empty());
+
+        let without_code = |genesis: astar_runtime::GenesisConfig| {
+            let mut json = serde_json::to_value(genesis).unwrap();
+            json["system"]
+                .as_object_mut()
+                .unwrap()
+                .remove("code")
+                .unwrap();
+            json
+        };
+        assert_eq!(without_code(light), without_code(dev_genesis()));
+    }
+
+    /// Builds the genesis storage of the dev spec, running the genesis build of every pallet.
+    ///
+    /// This is rather slow, but catches genesis configurations which serialize fine yet are
+    /// rejected by the runtime.
+    #[test]
+    fn dev_spec_genesis_storage_builds() {
+        let spec = get_chain_spec();
+        assert!(spec.build_storage().is_ok());
+        assert!(genesis_state_root(&spec).is_ok());
+    }
+
+    #[test]
+    fn dev_spec_matches_genesis_fixture() {
+        assert_matches_genesis_fixture(&get_chain_spec(), wasm_binary_unwrap());
+    }
+
+    #[test]
+    fn default_genesis_builder_matches_dev_genesis() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let explicit = AstarGenesisBuilder::default()
+            .balances(vec![
+                (alice.clone(), 1_000_000_000 * ASTR),
+                (bob, 1_000_000_000 * ASTR),
+            ])
+            .sudo(alice)
+            .para_id(ASTAR_PARA_ID)
+            .authorities(vec![
+                authority_keys_from_seed("Alice"),
+                authority_keys_from_seed("Bob"),
+            ])
+            .collator_selection(Default::default())
+            .vesting(vec![])
+            .inflation_params(Default::default())
+            .dapp_staking(Default::default())
+            .assets(vec![])
+            .build();
+
+        let default = serde_json::to_string(&dev_genesis()).unwrap();
+        assert_eq!(default, serde_json::to_string(&explicit).unwrap());
+
+        let spec = AstarChainSpecBuilder::default().build();
+        let from_spec = runtime_genesis(&spec).expect("spec holds a runtime genesis");
+        assert_eq!(default, serde_json::to_string(&from_spec).unwrap());
+    }
+
+    fn dev_dapp_staking_params() -> DappStakingParams {
+        let config = dev_genesis().dapp_staking;
+        DappStakingParams {
+            reward_portion: config.reward_portion,
+            slot_distribution: config.slot_distribution,
+            tier_thresholds: config.tier_thresholds,
+            slots_per_tier: config.slots_per_tier,
+        }
+    }
+
+    #[test]
+    fn dev_dapp_staking_params_are_valid() {
+        assert_eq!(dev_dapp_staking_params(), DappStakingParams::default());
+        assert_eq!(dev_dapp_staking_params().validate(), Ok(()));
+    }
+
+    #[test]
+    fn dev_dapp_staking_config_is_unchanged() {
+        // Economic policy, must only change deliberately
+        let config = dev_genesis().dapp_staking;
+        assert_eq!(
+            config.reward_portion,
+            vec![
+                Permill::from_percent(40),
+                Permill::from_percent(30),
+                Permill::from_percent(20),
+                Permill::from_percent(10),
+            ]
+        );
+        assert_eq!(
+            config.slot_distribution,
+            vec![
+                Permill::from_percent(10),
+                Permill::from_percent(20),
+                Permill::from_percent(30),
+                Permill::from_percent(40),
+            ]
+        );
+        assert_eq!(
+            config.tier_thresholds,
+            vec![
+                TierThreshold::DynamicTvlAmount {
+                    amount: 30000 * ASTR,
+                    minimum_amount: 20000 * ASTR,
+                },
+                TierThreshold::DynamicTvlAmount {
+                    amount: 7500 * ASTR,
+                    minimum_amount: 5000 * ASTR,
+                },
+                TierThreshold::DynamicTvlAmount {
+                    amount: 20000 * ASTR,
+                    minimum_amount: 15000 * ASTR,
+                },
+                TierThreshold::FixedTvlAmount {
+                    amount: 5000 * ASTR,
+                },
+            ]
+        );
+        assert_eq!(config.slots_per_tier, vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn dapp_staking_params_can_be_overridden() {
+        let params = DappStakingParams {
+            reward_portion: vec![
+                Permill::from_percent(50),
+                Permill::from_percent(30),
+                Permill::from_percent(15),
+                Permill::from_percent(5),
+            ],
+            slot_distribution: vec![
+                Permill::from_percent(10),
+                Permill::from_percent(20),
+                Permill::from_percent(30),
+                Permill::from_percent(40),
+            ],
+            tier_thresholds: vec![
+                TierThreshold::DynamicTvlAmount {
+                    amount: 10000 * ASTR,
+                    minimum_amount: 8000 * ASTR,
+                },
+                TierThreshold::FixedTvlAmount {
+                    amount: 3000 * ASTR,
+                },
+                TierThreshold::FixedTvlAmount {
+                    amount: 1000 * ASTR,
+                },
+                TierThreshold::FixedTvlAmount { amount: 500 * ASTR },
+            ],
+            slots_per_tier: vec![5, 10, 15, 20],
+        };
+        assert_eq!(params.validate(), Ok(()));
+
+        let config = AstarGenesisBuilder::default()
+            .dapp_staking(params.clone())
+            .build()
+            .dapp_staking;
+        assert_eq!(config.slots_per_tier, params.slots_per_tier);
+        assert_eq!(config.tier_thresholds, params.tier_thresholds);
+    }
+
+    #[test]
+    fn broken_dapp_staking_params_are_rejected() {
+        let mut params = dev_dapp_staking_params();
+        params.slots_per_tier.pop();
+        assert!(params
+            .validate()
+            .unwrap_err()
+            .starts_with("Tier vectors must have equal length"));
+
+        let mut params = dev_dapp_staking_params();
+        params.reward_portion = vec![Permill::from_percent(20); 5];
+        params.slot_distribution = vec![Permill::from_percent(20); 5];
+        params
+            .tier_thresholds
+            .push(TierThreshold::FixedTvlAmount { amount: ASTR });
+        params.slots_per_tier.push(50);
+        assert_eq!(
+            params.validate(),
+            Err("The runtime has 4 tiers, the configuration 5".into())
+        );
+
+        let mut params = dev_dapp_staking_params();
+        params.reward_portion[0] = Permill::from_percent(50);
+        assert_eq!(
+            params.validate(),
+            Err("Sum of reward_portion must be exactly 100%".into())
+        );
+
+        let mut params = dev_dapp_staking_params();
+        params.slot_distribution[3] = Permill::from_percent(30);
+        assert_eq!(
+            params.validate(),
+            Err("Sum of slot_distribution must be exactly 100%".into())
+        );
+
+        let mut params = dev_dapp_staking_params();
+        params.slots_per_tier = vec![10, 30, 20, 40];
+        assert_eq!(
+            params.validate(),
+            Err("slots_per_tier must be monotonically non-decreasing".into())
+        );
+
+        let mut params = dev_dapp_staking_params();
+        params.tier_thresholds[1] = TierThreshold::DynamicTvlAmount {
+            amount: 5000 * ASTR,
+            minimum_amount: 7500 * ASTR,
+        };
+        assert_eq!(
+            params.validate(),
+            Err(format!(
+                "Tier 2 threshold amount {} is below its minimum amount {}",
+                5000 * ASTR,
+                7500 * ASTR
+            ))
+        );
+    }
+
+    #[test]
+    fn dapp_staking_slots_are_bounded() {
+        let params = dev_dapp_staking_params();
+        assert_eq!(params.total_slots(), 100);
+        assert_eq!(max_dapp_staking_slots(), 500);
+        assert_eq!(
+            params.check_total_slots(1, max_dapp_staking_slots()),
+            Ok(())
+        );
+        assert!(AstarGenesisBuilder::default().validate().is_ok());
+
+        let mut zero_tier = dev_dapp_staking_params();
+        zero_tier.slots_per_tier = vec![0, 20, 30, 40];
+        assert_eq!(zero_tier.validate(), Err("Tier 1 has no slot".into()));
+
+        assert_eq!(
+            AstarGenesisBuilder::default()
+                .dapp_staking_total_slots(1, 50)
+                .validate(),
+            Err("Invalid dApp staking genesis configuration: Total of slots_per_tier 100 must be within [1, 50]".into())
+        );
+
+        let mut overflow = dev_dapp_staking_params();
+        overflow.slots_per_tier = vec![u16::MAX, u16::MAX, u16::MAX, u16::MAX];
+        assert_eq!(
+            overflow.check_total_slots(0, u32::MAX),
+            Err(format!(
+                "Total of slots_per_tier {} overflows the number of slots",
+                4 * u32::from(u16::MAX)
+            ))
+        );
+    }
+
+    #[test]
+    fn tier_thresholds_ordering_is_checked() {
+        let mut params = dev_dapp_staking_params();
+        assert_eq!(
+            params.check_thresholds_ordering(),
+            Err(format!(
+                "Tier 2 threshold {} is below the tier 3 threshold {}",
+                7500 * ASTR,
+                20000 * ASTR
+            ))
+        );
+
+        params.tier_thresholds.swap(1, 2);
+        assert_eq!(params.check_thresholds_ordering(), Ok(()));
+        assert_eq!(params.validate(), Ok(()));
+    }
+
+    #[test]
+    fn precompiles_are_seeded_with_revert_bytecode() {
+        let accounts = dev_genesis().evm.accounts;
+        assert_eq!(
+            accounts.keys().cloned().collect::<BTreeSet<_>>(),
+            Precompiles::used_addresses().collect::<BTreeSet<_>>()
+        );
+        assert!(accounts
+            .values()
+            .all(|account| account.code == EVM_REVERT_BYTECODE));
+    }
+
+    #[test]
+    fn every_used_precompile_has_code_in_genesis() {
+        let accounts = dev_genesis().evm.accounts;
+        for address in Precompiles::used_addresses() {
+            let account = accounts
+                .get(&address)
+                .unwrap_or_else(|| panic!("precompile {:?} isn't seeded", address));
+            assert!(
+                !account.code.is_empty(),
+                "precompile {:?} is seeded without code",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn evm_accounts_are_predeployed() {
+        let address = H160::from_low_u64_be(0x1234);
+        let contract = fp_evm::GenesisAccount {
+            nonce: Default::default(),
+            balance: 1_000.into(),
+            storage: Default::default(),
+            code: vec![0x60, 0x00],
+        };
+        let precompile = Precompiles::used_addresses().next().unwrap();
+
+        let accounts = AstarGenesisBuilder::default()
+            .evm_accounts(vec![
+                (address, contract.clone()),
+                (precompile, contract.clone()),
+            ])
+            .build()
+            .evm
+            .accounts;
+        for address in [address, precompile] {
+            assert_eq!(accounts[&address].code, contract.code);
+            assert_eq!(accounts[&address].balance, contract.balance);
+        }
+        assert_eq!(accounts.len(), Precompiles::used_addresses().count() + 1);
+
+        let invalid = AstarGenesisBuilder::default().evm_accounts(vec![(
+            address,
+            fp_evm::GenesisAccount {
+                code: vec![],
+                ..contract
+            },
+        )]);
+        assert_eq!(
+            invalid.validate(),
+            Err(format!(
+                "Invalid EVM genesis configuration: EVM account {:?} has no code",
+                address
+            ))
+        );
+    }
+
+    #[test]
+    fn precompiles_are_seeded_with_code_only() {
+        let accounts = dev_genesis().evm.accounts;
+        for address in Precompiles::used_addresses() {
+            let account = &accounts[&address];
+            assert!(account.nonce.is_zero(), "{:?} has a nonce", address);
+            assert!(account.balance.is_zero(), "{:?} has a balance", address);
+            assert!(account.storage.is_empty(), "{:?} has storage", address);
+        }
+    }
+
+    #[test]
+    fn evm_accounts_are_empty_when_evm_is_disabled() {
+        let genesis = AstarGenesisBuilder::default().enable_evm(false).build();
+        assert!(genesis.evm.accounts.is_empty());
+
+        let genesis = AstarGenesisBuilder::default()
+            .precompile_addresses(vec![H160::from_low_u64_be(1)])
+            .enable_evm(false)
+            .build();
+        assert!(genesis.evm.accounts.is_empty());
+    }
+
+    #[test]
+    fn seeded_precompile_addresses_match_runtime() {
+        let addresses = seeded_precompile_addresses();
+        assert_eq!(addresses.len(), Precompiles::used_addresses().count());
+        assert_eq!(
+            dev_genesis()
+                .evm
+                .accounts
+                .into_keys()
+                .collect::<BTreeSet<_>>(),
+            addresses.into_iter().collect::<BTreeSet<_>>()
+        );
+    }
+
+    #[test]
+    fn seeded_precompile_addresses_are_sorted() {
+        let addresses = seeded_precompile_addresses();
+        assert!(addresses.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            dev_genesis().evm.accounts.into_keys().collect::<Vec<_>>(),
+            addresses
+        );
+    }
+
+    #[test]
+    fn raw_chain_spec_holds_code() {
+        let raw = get_chain_spec_raw().unwrap();
+        // Hex encoded `:code`.
+        assert!(raw.contains("\"0x3a636f6465\""));
+    }
+
+    #[test]
+    fn genesis_logs_total_issuance() {
+        capture_logs();
+
+        // An amount unlikely to be endowed by any other test.
+        let amount = 123_456_789 * ASTR;
+        AstarGenesisBuilder::default()
+            .balances(vec![
+                (get_account_id_from_seed::<sr25519::Public>("Alice"), amount),
+                (get_account_id_from_seed::<sr25519::Public>("Bob"), amount),
+            ])
+            .build();
+
+        let expected = format!("Genesis endows 2 accounts, total issuance {}", 2 * amount);
+        assert!(LOGS.lock().unwrap().contains(&expected));
+    }
+
+    #[test]
+    fn bad_blocks_are_set_in_extensions() {
+        assert_eq!(
+            Extensions::try_get(&get_chain_spec()).unwrap().bad_blocks,
+            None
+        );
+
+        let hash = H256::repeat_byte(0xab);
+        let content = format!("# Known bad blocks\n\n{:?}\n", hash);
+        let bad_blocks = bad_blocks_from_str(&content).unwrap();
+        assert_eq!(bad_blocks, vec![hash]);
+
+        let spec = AstarChainSpecBuilder::default()
+            .bad_blocks(bad_blocks)
+            .build();
+        let json = spec.as_json(false).unwrap();
+        let spec = AstarChainSpec::from_json_bytes(json.into_bytes()).unwrap();
+        let extensions = Extensions::try_get(&spec).unwrap();
+        assert_eq!(extensions.bad_blocks, Some([hash].into_iter().collect()));
+    }
+
+    #[test]
+    fn malformed_bad_blocks_are_reported() {
+        let errors = bad_blocks_from_str("0x1234\nnot-hex").unwrap_err();
+        let errors = errors.lines().collect::<Vec<_>>();
+        assert_eq!(
+            errors[0],
+            "line 1: Invalid block hash `0x1234`: expected 32 bytes, found 2"
+        );
+        assert!(errors[1].starts_with("line 2: Invalid block hash `not-hex`"));
+    }
+
+    #[test]
+    fn spec_wraps_prebuilt_genesis() {
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let mut genesis = dev_genesis();
+        genesis.sudo.key = Some(bob.clone());
+        let extensions = Extensions {
+            bad_blocks: None,
+            relay_chain: RelayChain::Rococo.into(),
+            para_id: 3000,
+            fork_id: None,
+        };
+
+        let spec = spec_from_genesis_config(
+            genesis,
+            "Astar Harness",
+            "astar-harness",
+            ChainType::Local,
+            extensions.clone(),
+        );
+        assert_eq!(spec.name(), "Astar Harness");
+        assert_eq!(spec.id(), "astar-harness");
+        assert_eq!(spec.extensions(), &extensions);
+        assert_eq!(runtime_genesis(&spec).unwrap().sudo.key, Some(bob));
+    }
+
+    #[test]
+    fn dev_genesis_total_issuance() {
+        assert_eq!(
+            genesis_total_issuance(&get_chain_spec()),
+            Ok(2_000_000_000 * ASTR)
+        );
+    }
+
+    #[test]
+    fn genesis_accounts_are_listed_as_ss58() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let spec = AstarChainSpecBuilder::default()
+            .genesis(
+                AstarGenesisBuilder::default()
+                    .balances(vec![(alice, 1_000 * ASTR)])
+                    .authorities(vec![authority_keys_from_seed("Alice")])
+                    .collator_selection(CollatorSelectionParams {
+                        desired_candidates: 1,
+                        candidacy_bond: 100 * ASTR,
+                    }),
+            )
+            .build();
+
+        assert_eq!(
+            genesis_accounts_ss58(&spec, SS58Prefix::get().into()),
+            Ok(vec![(
+                "ajYMsCKsEAhEvHpeA4XqsfiA9v1CdzZPrCfS6pEfeGHW9j8".to_string(),
+                1_000 * ASTR
+            )])
+        );
+    }
+
+    #[test]
+    fn treasury_can_be_endowed() {
+        let treasury = pallet_account(TreasuryPalletId::get());
+        assert_eq!(treasury, astar_runtime::TreasuryAccountId::get());
+        assert!(treasury.as_slice().starts_with(b"modlpy/trsry"));
+
+        let genesis = AstarGenesisBuilder::default()
+            .treasury_endowment(1_000 * ASTR)
+            .build();
+        assert!(genesis
+            .balances
+            .balances
+            .contains(&(treasury.clone(), 1_000 * ASTR)));
+
+        assert!(AstarGenesisBuilder::default()
+            .balances(vec![(treasury, 1)])
+            .treasury_endowment(1_000 * ASTR)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn evm_accounts_can_be_funded() {
+        let address = H160::repeat_byte(0xaa);
+        let mapped = evm_mapped_account(address);
+        assert_eq!(
+            mapped,
+            AccountId::from(sp_core::blake2_256(
+                &[b"evm:".as_slice(), address.as_bytes()].concat()
+            ))
+        );
+
+        let genesis = AstarGenesisBuilder::default()
+            .evm_funded_accounts(vec![(address, 1_000 * ASTR)])
+            .build_light();
+        assert!(genesis.balances.balances.contains(&(mapped, 1_000 * ASTR)));
+    }
+
+    #[test]
+    fn endowments_are_capped() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let within = AstarGenesisBuilder::default()
+            .max_per_account(1_000_000_000 * ASTR)
+            .max_total_issuance(2_000_000_000 * ASTR);
+        assert!(within.validate().is_ok());
+
+        assert_eq!(
+            within.clone().max_per_account(1_000 * ASTR).validate(),
+            Err(format!(
+                "Invalid balances genesis configuration: {} is endowed with {}, more than the cap {}",
+                alice,
+                1_000_000_000 * ASTR,
+                1_000 * ASTR
+            ))
+        );
+        assert_eq!(
+            within.max_total_issuance(1_000_000_000 * ASTR).validate(),
+            Err(format!(
+                "Invalid balances genesis configuration: Total issuance {} exceeds the cap {}",
+                2_000_000_000 * ASTR,
+                1_000_000_000 * ASTR
+            ))
+        );
+    }
+
+    #[test]
+    fn candidacy_can_be_open_from_genesis() {
+        let genesis = AstarGenesisBuilder::default().invulnerables(false).build();
+        assert!(genesis.collator_selection.invulnerables.is_empty());
+        assert_eq!(genesis.session.keys.len(), 2);
+
+        // Collators don't need to afford the candidacy bond if they aren't invulnerable.
+        let builder = AstarGenesisBuilder::default()
+            .balances(vec![])
+            .invulnerables(false);
+        assert!(builder.validate().is_ok());
+        assert!(builder.invulnerables(true).validate().is_err());
+
+        let error = AstarGenesisBuilder::default()
+            .invulnerables(false)
+            .collators(vec![])
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Invalid collator selection genesis configuration: No collator to author blocks"
+        );
+    }
+
+    #[test]
+    fn collators_are_only_registered_through_session() {
+        let genesis = dev_genesis();
+        assert!(genesis.aura.authorities.is_empty());
+        assert_eq!(
+            genesis
+                .session
+                .keys
+                .into_iter()
+                .map(|(_, collator, keys)| (collator, keys.aura))
+                .collect::<Vec<_>>(),
+            vec![
+                authority_keys_from_seed("Alice"),
+                authority_keys_from_seed("Bob")
+            ]
+        );
+    }
+
+    #[test]
+    fn dev_spec_is_valid() {
+        assert_eq!(validate_spec(&get_chain_spec()), Ok(()));
+    }
+
+    #[test]
+    fn extensions_para_id_matches_genesis() {
+        for spec in [get_chain_spec(), get_chain_spec_for_para_id(3000)] {
+            let genesis = runtime_genesis(&spec).unwrap();
+            assert_eq!(
+                spec.extensions().para_id,
+                u32::from(genesis.parachain_info.parachain_id)
+            );
+        }
+    }
+
+    #[test]
+    fn all_spec_violations_are_reported() {
+        let mut genesis = dev_genesis();
+        genesis.dapp_staking.reward_portion = vec![Permill::from_percent(10); 4];
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        genesis.balances.balances.retain(|(who, _)| *who != bob);
+        let precompile = Precompiles::used_addresses().next().unwrap();
+        genesis.evm.accounts.remove(&precompile);
+
+        let genesis = serde_json::to_string(&genesis).unwrap();
+        let spec = AstarChainSpec::from_genesis(
+            "Astar Testnet",
+            "astar",
+            ChainType::Development,
+            move || serde_json::from_str(&genesis).unwrap(),
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            Default::default(),
+        );
+
+        assert_eq!(
+            validate_spec(&spec),
+            Err(vec![
+                ValidationError::ParaIdMismatch {
+                    extensions: Some(0),
+                    genesis: ASTAR_PARA_ID,
+                },
+                ValidationError::DappStaking("Sum of reward_portion must be exactly 100%".into()),
+                ValidationError::UnderfundedInvulnerable {
+                    who: bob,
+                    balance: 0,
+                    candidacy_bond: CollatorSelectionParams::default().candidacy_bond,
+                },
+                ValidationError::UnseededPrecompile(precompile),
+            ])
+        );
+    }
+
+    #[test]
+    fn tx_fee_multiplier_is_set() {
+        let multiplier = Multiplier::saturating_from_integer(3);
+        let genesis = AstarGenesisBuilder::default()
+            .tx_fee_multiplier(multiplier)
+            .build();
+        assert_eq!(genesis.transaction_payment.multiplier, multiplier);
+
+        assert_eq!(
+            dev_genesis().transaction_payment.multiplier,
+            Multiplier::saturating_from_integer(1)
+        );
+        assert!(AstarGenesisBuilder::default()
+            .tx_fee_multiplier(Multiplier::from_inner(0))
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn astr_amounts_are_parsed() {
+        assert_eq!(parse_astr_amount("1000"), Ok(1_000 * ASTR));
+        assert_eq!(parse_astr_amount("1000.5"), Ok(1_000 * ASTR + ASTR / 2));
+        assert_eq!(parse_astr_amount("0.000000000000000001"), Ok(1));
+
+        assert_eq!(
+            parse_astr_amount("0.0000000000000000001"),
+            Err(
+                "Invalid ASTR amount `0.0000000000000000001`: more than 18 fractional digits"
+                    .into()
+            )
+        );
+        let too_large = (Balance::MAX / ASTR + 1).to_string();
+        assert_eq!(
+            parse_astr_amount(&too_large),
+            Err(format!(
+                "Invalid ASTR amount `{}`: overflows the balance type",
+                too_large
+            ))
+        );
+        for malformed in ["", ".5", "1.", "1,5", "-1", "1e3"] {
+            assert!(parse_astr_amount(malformed)
+                .unwrap_err()
+                .ends_with("not a decimal number"));
+        }
+    }
+
+    #[test]
+    fn account_is_decoded_from_hex() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bytes: [u8; 32] = alice.clone().into();
+        let hex = format!("0x{}", sp_core::hexdisplay::HexDisplay::from(&bytes));
+        assert_eq!(account_from_hex(&hex), Ok(alice));
+
+        assert_eq!(
+            account_from_hex("0x1234"),
+            Err("Invalid account `0x1234`: expected 32 bytes, found 2".into())
+        );
+        assert!(account_from_hex("0xnot-hex")
+            .unwrap_err()
+            .starts_with("Invalid account `0xnot-hex`"));
+    }
+
+    #[test]
+    fn dapp_staking_setup_calls_are_built() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let dapp = SmartContract::Evm(H160::repeat_byte(0x01));
+        let genesis = AstarGenesisBuilder::default();
+
+        assert_eq!(
+            genesis.dapp_staking_setup_calls(
+                vec![(dapp.clone(), alice.clone())],
+                vec![(bob.clone(), dapp.clone(), 1_000 * ASTR)],
+            ),
+            Ok(DappStakingSetup {
+                root: vec![RuntimeCall::DappStaking(
+                    pallet_dapp_staking_v3::Call::register {
+                        owner: alice.clone(),
+                        smart_contract: dapp.clone(),
+                    }
+                )],
+                signed: vec![
+                    (
+                        bob.clone(),
+                        RuntimeCall::DappStaking(pallet_dapp_staking_v3::Call::lock {
+                            amount: 1_000 * ASTR
+                        }),
+                    ),
+                    (
+                        bob.clone(),
+                        RuntimeCall::DappStaking(pallet_dapp_staking_v3::Call::stake {
+                            smart_contract: dapp.clone(),
+                            amount: 1_000 * ASTR,
+                        }),
+                    ),
+                ],
+            })
+        );
+
+        assert_eq!(
+            genesis.dapp_staking_setup_calls(
+                vec![(dapp.clone(), alice)],
+                vec![(bob.clone(), dapp, 2_000_000_000 * ASTR)],
+            ),
+            Err(format!(
+                "Staker {} is endowed with {}, less than its stakes {}",
+                bob,
+                1_000_000_000 * ASTR,
+                2_000_000_000 * ASTR
+            ))
+        );
+    }
+
+    #[test]
+    fn foreign_asset_setup_calls_are_built() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let asset = AssetGenesisEntry {
+            id: 1,
+            admin: alice,
+            is_sufficient: true,
+            min_balance: 1_000,
+            name: b"Relay Token".to_vec(),
+            symbol: b"DOT".to_vec(),
+            decimals: 10,
+        };
+        let genesis = AstarGenesisBuilder::default().assets(vec![asset]);
+        let relay = VersionedMultiLocation::V3(MultiLocation::parent());
+
+        assert_eq!(
+            genesis.foreign_asset_setup_calls(vec![(1, relay.clone())]),
+            Ok(vec![RuntimeCall::XcAssetConfig(
+                pallet_xc_asset_config::Call::register_asset_location {
+                    asset_location: Box::new(relay.clone()),
+                    asset_id: 1,
+                }
+            )])
+        );
+        assert_eq!(
+            genesis.foreign_asset_setup_calls(vec![(2, relay.clone())]),
+            Err("Foreign asset 2 isn't registered".into())
+        );
+        assert!(genesis
+            .foreign_asset_setup_calls(vec![(1, relay.clone()), (1, relay)])
+            .is_err());
+    }
+
+    #[test]
+    fn single_collator_chain_spec_is_built() {
+        let genesis = runtime_genesis(&get_single_collator_chain_spec()).unwrap();
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+
+        assert_eq!(genesis.collator_selection.invulnerables, vec![alice]);
+        assert_eq!(genesis.collator_selection.desired_candidates, 1);
+        assert_eq!(genesis.collator_selection.candidacy_bond, 1_000 * ASTR);
+        assert_eq!(genesis.session.keys.len(), 1);
+    }
+
+    #[test]
+    fn duplicate_balances_are_handled() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+        let clean = vec![(alice.clone(), 1), (bob.clone(), 2)];
+        assert_eq!(dedup_balances(clean.clone(), false), Ok(clean.clone()));
+        assert_eq!(dedup_balances(clean.clone(), true), Ok(clean));
+
+        let duplicate = vec![(alice.clone(), 1), (bob.clone(), 2), (alice.clone(), 3)];
+        assert_eq!(
+            dedup_balances(duplicate.clone(), false),
+            Err(DuplicateAccount(alice.clone()))
+        );
+        assert_eq!(
+            dedup_balances(duplicate.clone(), true),
+            Ok(vec![(alice.clone(), 4), (bob, 2)])
+        );
+        assert_eq!(
+            AstarGenesisBuilder::default()
+                .balances(duplicate)
+                .validate(),
+            Err(format!(
+                "Invalid balances genesis configuration: Account {} is endowed more than once",
+                alice
+            ))
+        );
+    }
+
+    #[test]
+    fn chain_spec_errors_are_reported() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let try_build = |genesis| {
+            AstarChainSpecBuilder::default()
+                .genesis(genesis)
+                .try_build()
+                .map(|_| ())
+        };
+
+        assert!(matches!(
+            get_chain_spec_from_file(Path::new("does-not-exist.json")),
+            Err(ChainSpecError::InvalidFile(_))
+        ));
+        assert!(matches!(
+            get_chain_spec_from_collators_csv(Path::new("does-not-exist.csv")),
+            Err(ChainSpecError::InvalidFile(_))
+        ));
+        assert_eq!(
+            try_build(AstarGenesisBuilder::default().balances(vec![
+                (alice.clone(), 1_000_000_000 * ASTR),
+                (alice.clone(), 1_000_000_000 * ASTR),
+            ])),
+            Err(ChainSpecError::DuplicateAccount(alice.clone()))
+        );
+
+        let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+        let mut balances = default_endowment();
+        balances.push((charlie.clone(), 0));
+        assert_eq!(
+            try_build(AstarGenesisBuilder::default().balances(balances)),
+            Err(ChainSpecError::InvalidBalance(charlie))
+        );
+
+        assert!(matches!(
+            try_build(
+                AstarGenesisBuilder::default().balances(vec![(alice, 1_000_000_000 * ASTR)])
+            ),
+            Err(ChainSpecError::ValidationFailed(errors))
+                if errors[0].starts_with("Invalid collator selection genesis configuration")
+        ));
+        assert_eq!(try_build(AstarGenesisBuilder::default()), Ok(()));
+    }
+
+    #[test]
+    fn dev_spec_aura_authorities() {
+        assert_eq!(
+            spec_aura_authorities(&get_chain_spec()),
+            Ok(vec![
+                authority_keys_from_seed("Alice"),
+                authority_keys_from_seed("Bob"),
+            ])
+        );
+    }
+
+    #[test]
+    fn dry_run_matches_full_build() {
+        assert_eq!(
+            AstarGenesisBuilder::default().dry_run(),
+            Ok(GenesisReport {
+                endowed_accounts: 2,
+                issuance: 2_000_000_000 * ASTR,
+                invulnerables: 2,
+                dapp_staking: Default::default(),
+            })
+        );
+
+        let mut genesis = AstarGenesisBuilder::default().build_with_code(vec![]);
+        assert!(genesis.system.code.is_empty());
+        genesis.system.code = wasm_binary_unwrap().to_vec();
+        assert_eq!(
+            serde_json::to_string(&genesis).unwrap(),
+            serde_json::to_string(&dev_genesis()).unwrap()
+        );
+
+        let invalid = AstarGenesisBuilder::default().dapp_staking(DappStakingParams {
+            slots_per_tier: vec![40, 30, 20, 10],
+            ..Default::default()
+        });
+        assert_eq!(
+            invalid.clone().dry_run(),
+            Err(invalid.validate().unwrap_err())
+        );
+    }
+
+    #[test]
+    fn faucets_are_endowed() {
+        let genesis = AstarGenesisBuilder::default()
+            .faucets(&["Charlie", "Dave"], 1_000 * ASTR)
+            .build();
+
+        let mut expected = default_endowment();
+        expected.push((
+            get_account_id_from_seed::<sr25519::Public>("Charlie"),
+            1_000 * ASTR,
+        ));
+        expected.push((
+            get_account_id_from_seed::<sr25519::Public>("Dave"),
+            1_000 * ASTR,
+        ));
+        assert_eq!(genesis.balances.balances, expected);
+    }
+
+    #[test]
+    fn sudo_key_can_be_omitted() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        assert_eq!(dev_genesis().sudo.key, Some(alice));
+
+        let spec = AstarChainSpecBuilder::default()
+            .genesis(AstarGenesisBuilder::default().without_sudo())
+            .build();
+        let genesis = runtime_genesis(&spec).expect("spec holds a runtime genesis");
+        assert_eq!(genesis.sudo.key, None);
+        assert!(spec.build_storage().is_ok());
+    }
+
+    #[test]
+    fn sudo_can_be_a_multisig() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+
+        let multisig =
+            account_from_hex("0x49daa32c7287890f38b7e1a8cd2961723d36d20baa0bf3b82e0c4bdda93b1c0a")
+                .unwrap();
+        assert_eq!(
+            multisig_account_id(&[alice.clone(), bob.clone(), charlie.clone()], 2),
+            multisig
+        );
+        assert_eq!(
+            multisig_account_id(
+                &[charlie.clone(), alice.clone(), bob.clone(), alice.clone()],
+                2
+            ),
+            multisig
+        );
+        assert_ne!(
+            multisig_account_id(&[alice.clone(), bob.clone(), charlie.clone()], 3),
+            multisig
+        );
+
+        let genesis = AstarGenesisBuilder::default()
+            .sudo_multisig(&[alice, bob, charlie], 2)
+            .build();
+        assert_eq!(genesis.sudo.key, Some(multisig));
+    }
+
+    #[test]
+    fn sudo_key_is_resolved_from_env() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+        assert_eq!(sudo_key_from_env(None), alice);
+        assert_eq!(sudo_key_from_env(Some(bob.to_ss58check())), bob);
+
+        assert_eq!(
+            sudo_key_from_env(Some(
+                bob.to_ss58check_with_version(u16::from(SS58Prefix::get()).into())
+            )),
+            bob
+        );
+
+        // Invalid or foreign addresses, e.g. a Kusama one, fall back to the default key
+        assert_eq!(sudo_key_from_env(Some("not-an-address".into())), alice);
+        assert_eq!(
+            sudo_key_from_env(Some(bob.to_ss58check_with_version(2u16.into()))),
+            alice
+        );
+    }
+
+    #[test]
+    fn protocol_id_is_set() {
+        assert_eq!(get_chain_spec().protocol_id(), Some("astar"));
+
+        let spec = AstarChainSpecBuilder::default()
+            .protocol_id("astar-fork")
+            .build();
+        assert_eq!(spec.protocol_id(), Some("astar-fork"));
+    }
+
+    #[test]
+    fn live_chain_spec_is_built() {
+        let boot_node: MultiaddrWithPeerId =
+            "/ip4/127.0.0.1/tcp/30333/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp"
+                .parse()
+                .unwrap();
+        let sudo_key = get_account_id_from_seed::<sr25519::Public>("Astar");
+        let collator = authority_keys_from_seed("Collator");
+        let balances = vec![
+            (sudo_key.clone(), 1_000_000 * ASTR),
+            (collator.0.clone(), 10_000_000 * ASTR),
+        ];
+        let spec = get_live_chain_spec(
+            vec![boot_node.clone()],
+            None,
+            sudo_key.clone(),
+            vec![collator.clone()],
+            balances.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(spec.chain_type(), ChainType::Live);
+        assert_eq!(spec.boot_nodes(), &[boot_node][..]);
+        assert_eq!(spec.protocol_id(), Some("astar"));
+        assert!(spec.telemetry_endpoints().is_none());
+        assert!(spec.id().starts_with("astar"));
+
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let alice_stash = get_account_id_from_seed::<sr25519::Public>("Alice//stash");
+        let dev_account = Err(ChainSpecError::InvalidAccount(format!(
+            "Dev account {} can't be used in a live chain specification",
+            alice
+        )));
+        assert_eq!(
+            get_live_chain_spec(
+                vec![],
+                None,
+                alice.clone(),
+                vec![collator.clone()],
+                balances.clone()
+            )
+            .map(|_| ()),
+            dev_account
+        );
+        assert_eq!(
+            get_live_chain_spec(
+                vec![],
+                None,
+                sudo_key.clone(),
+                vec![authority_keys_from_seed("Alice")],
+                balances.clone()
+            )
+            .map(|_| ()),
+            dev_account
+        );
+        assert!(get_live_chain_spec(
+            vec![],
+            None,
+            sudo_key,
+            vec![collator],
+            [balances, vec![(alice_stash, ASTR)]].concat()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn telemetry_url_is_set() {
+        const TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
+
+        let spec = AstarChainSpecBuilder::default()
+            .telemetry_url(Some((TELEMETRY_URL.into(), 0)))
+            .unwrap()
+            .build();
+        assert_eq!(
+            serde_json::to_value(spec.telemetry_endpoints()).unwrap(),
+            serde_json::to_value(Some(
+                TelemetryEndpoints::new(vec![(TELEMETRY_URL.into(), 0)]).unwrap()
+            ))
+            .unwrap()
+        );
+
+        // Dev spec has no telemetry
+        assert!(get_chain_spec().telemetry_endpoints().is_none());
+
+        // Only websocket endpoints are accepted
+        for url in ["https://telemetry.polkadot.io/submit/", "not a url"] {
+            assert!(AstarChainSpecBuilder::default()
+                .telemetry_url(Some((url.into(), 0)))
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn properties_are_built() {
+        let properties = build_properties("TST", 12, 42);
+        assert_eq!(properties.len(), 3);
+        assert_eq!(properties["tokenSymbol"], serde_json::json!("TST"));
+        assert_eq!(properties["tokenDecimals"], serde_json::json!(12));
+        assert_eq!(properties["ss58Format"], serde_json::json!(42));
+
+        let properties = get_chain_spec().properties();
+        assert_eq!(properties["tokenSymbol"], serde_json::json!("ASTR"));
+        assert_eq!(properties["tokenDecimals"], serde_json::json!(18));
+        assert_eq!(properties["ss58Format"], serde_json::json!(5));
+    }
+
+    #[test]
+    fn collators_csv_fixture_is_loaded() {
+        let collators = load_collators_csv(Path::new(COLLATORS_FIXTURE)).unwrap();
+
+        let (alice, alice_aura) = authority_keys_from_seed("Alice");
+        let (bob, bob_aura) = authority_keys_from_seed("Bob");
+        let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+        assert_eq!(
+            collators,
+            vec![
+                (alice.clone(), charlie.clone(), alice_aura.clone()),
+                (bob.clone(), bob.clone(), bob_aura.clone()),
+            ]
+        );
+
+        let genesis = AstarGenesisBuilder::default().collators(collators).build();
+        assert_eq!(
+            genesis.collator_selection.invulnerables,
+            vec![alice.clone(), bob.clone()]
+        );
+        assert_eq!(
+            genesis.session.keys,
+            vec![
+                (
+                    charlie,
+                    alice,
+                    session_keys(CollatorSessionKeys { aura: alice_aura })
+                ),
+                (
+                    bob.clone(),
+                    bob,
+                    session_keys(CollatorSessionKeys { aura: bob_aura })
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn session_keys_hold_the_aura_key() {
+        let aura = get_from_seed::<AuraId>("Alice");
+        let keys = session_keys(CollatorSessionKeys { aura: aura.clone() });
+        assert_eq!(keys.aura, aura);
+    }
+
+    #[test]
+    fn aura_keys_round_trip_through_hex_and_ss58() {
+        let aura = get_from_seed::<AuraId>("Alice");
+        let hex = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+        let ss58 = "ajYMsCKsEAhEvHpeA4XqsfiA9v1CdzZPrCfS6pEfeGHW9j8";
+
+        assert_eq!(aura_id_from_hex(hex), Ok(aura.clone()));
+        assert_eq!(aura_id_to_ss58(&aura), ss58);
+        assert_eq!(parse_aura_id(ss58), Ok(aura.clone()));
+        assert_eq!(parse_aura_id(hex), Ok(aura.clone()));
+        assert_eq!(
+            aura_id_from_hex(&sp_core::bytes::to_hex(aura.as_slice(), false)),
+            Ok(aura)
+        );
+
+        assert!(aura_id_from_hex("0x1234")
+            .unwrap_err()
+            .contains("expected 32 bytes, found 2"));
+        assert!(parse_aura_id("not-an-address").is_err());
+    }
+
+    #[test]
+    fn chain_spec_from_collators_csv_builds() {
+        get_chain_spec_from_collators_csv(Path::new(COLLATORS_FIXTURE))
+            .unwrap()
+            .build_storage()
+            .unwrap();
+    }
+
+    #[test]
+    fn malformed_collators_csv_lines_are_reported() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice").to_ss58check();
+        let content = format!(
+            "# stash,controller,aura\n{alice},{alice}\n\n{alice},{alice},0x1234\n{alice},{alice},0xzz\nbad,{alice},0x00\n"
+        );
+
+        let errors = collators_from_csv(&content).unwrap_err();
+        let errors = errors.lines().collect::<Vec<_>>();
+        assert_eq!(errors.len(), 4);
+        assert_eq!(errors[0], "line 2: expected 3 fields, found 2");
+        assert!(errors[1].starts_with("line 4: Invalid Aura key `0x1234`: expected 32 bytes"));
+        assert!(errors[2].starts_with("line 5: Invalid Aura key `0xzz`"));
+        assert!(errors[3].starts_with("line 6: Invalid SS58 address `bad`"));
+    }
+
+    #[test]
+    fn dev_accounts_are_deterministic() {
+        let accounts = dev_accounts(100);
+        assert_eq!(accounts.len(), 100);
+        assert_eq!(accounts, dev_accounts(100));
+        assert!(accounts
+            .iter()
+            .all(|(_, balance)| *balance == DEV_ACCOUNT_BALANCE));
+        assert_eq!(
+            accounts[7].0,
+            get_account_id_from_seed::<sr25519::Public>("dev-7")
+        );
+
+        // All accounts are distinct
+        let unique = accounts.iter().map(|(who, _)| who).collect::<BTreeSet<_>>();
+        assert_eq!(unique.len(), 100);
+    }
+
+    #[test]
+    fn funded_keypairs_match_genesis_entries() {
+        let (balances, pairs) = funded_keypairs(20, 42 * ASTR);
+        assert_eq!(balances.len(), 20);
+        assert_eq!(pairs.len(), 20);
+
+        for ((who, balance), pair) in balances.iter().zip(&pairs) {
+            assert_eq!(*who, AccountPublic::from(pair.public()).into_account());
+            assert_eq!(*balance, 42 * ASTR);
+        }
+        assert_eq!(
+            balances.iter().map(|(who, _)| who).collect::<Vec<_>>(),
+            dev_accounts(20)
+                .iter()
+                .map(|(who, _)| who)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn collator_selection_params_are_applied() {
+        let config = dev_genesis().collator_selection;
+        assert_eq!(config.desired_candidates, 32);
+        assert_eq!(config.candidacy_bond, 3_200_000 * ASTR);
+
+        let params = CollatorSelectionParams {
+            desired_candidates: 4,
+            candidacy_bond: 100 * ASTR,
+        };
+        assert!(params.is_affordable(100 * ASTR));
+        assert!(!params.is_affordable(99 * ASTR));
+
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let config = AstarGenesisBuilder::default()
+            .balances(vec![(alice.clone(), 1_000 * ASTR)])
+            .authorities(vec![authority_keys_from_seed("Alice")])
+            .collator_selection(params)
+            .build()
+            .collator_selection;
+        assert_eq!(config.desired_candidates, 4);
+        assert_eq!(config.candidacy_bond, 100 * ASTR);
+    }
+
+    #[test]
+    fn candidacy_bond_can_be_a_share_of_issuance() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let builder = AstarGenesisBuilder::default()
+            .balances(vec![(alice, 600_000 * ASTR), (bob, 400_000 * ASTR)])
+            .candidacy_bond(CandidacyBond::PercentOfIssuance(Permill::from_percent(1)));
+        assert_eq!(
+            builder
+                .clone()
+                .build_light()
+                .collator_selection
+                .candidacy_bond,
+            10_000 * ASTR
+        );
+
+        let error = builder
+            .candidacy_bond(CandidacyBond::Absolute(0))
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Invalid collator selection genesis configuration: Candidacy bond is zero"
+        );
+    }
+
+    #[test]
+    fn extra_endowed_accounts_are_merged() {
+        let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+        let spec = with_extra_endowed(get_chain_spec(), vec![(charlie.clone(), 42 * ASTR)])
+            .expect("dev spec holds a runtime genesis");
+
+        let mut expected = default_endowment();
+        expected.push((charlie, 42 * ASTR));
+        assert_eq!(genesis_balances(&spec), expected);
+        assert_eq!(spec.id(), get_chain_spec().id());
+        assert_eq!(spec.extensions().para_id, ASTAR_PARA_ID);
+    }
+
+    #[test]
+    fn extra_endowed_duplicates_are_accumulated() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let spec = with_extra_endowed(
+            get_chain_spec(),
+            vec![(alice.clone(), ASTR), (alice.clone(), 2 * ASTR)],
+        )
+        .expect("dev spec holds a runtime genesis");
+
+        assert_eq!(
+            genesis_balances(&spec),
+            vec![
+                (alice.clone(), 1_000_000_003 * ASTR),
+                (bob, 1_000_000_000 * ASTR)
+            ]
+        );
+
+        assert!(with_extra_endowed(spec, vec![(alice, Balance::MAX)]).is_err());
+    }
+
+    #[test]
+    fn extra_endowed_requires_runtime_genesis() {
+        let raw = get_chain_spec()
+            .as_json(true)
+            .expect("dev spec can be serialized");
+        let raw = AstarChainSpec::from_json_bytes(raw.into_bytes()).expect("raw spec is valid");
+
+        assert!(with_extra_endowed(raw, vec![]).is_err());
+    }
+
+    fn genesis_balances(spec: &AstarChainSpec) -> Vec<(AccountId, Balance)> {
+        runtime_genesis(spec)
+            .expect("spec holds a runtime genesis")
+            .balances
+            .balances
+    }
+
+    #[test]
+    fn chain_spec_is_deterministic() {
+        // Not `get_chain_spec`, the sudo key might be overridden via the environment
+        let build = |precompiles: Vec<H160>| {
+            AstarChainSpecBuilder::default()
+                .genesis(AstarGenesisBuilder::default().precompile_addresses(precompiles))
+                .build()
+        };
+        let precompiles = seeded_precompile_addresses();
+        let mut reversed = precompiles.clone();
+        reversed.reverse();
+
+        // Seeding order doesn't leak into the serialized spec
+        let (spec, other) = (build(precompiles), build(reversed));
+        assert_eq!(
+            spec.as_json(false).unwrap().into_bytes(),
+            other.as_json(false).unwrap().into_bytes()
+        );
+        assert_eq!(
+            spec.as_json(true).unwrap().into_bytes(),
+            other.as_json(true).unwrap().into_bytes()
+        );
+    }
+
+    #[test]
+    fn genesis_diff_reports_changed_fields() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        // Not `get_chain_spec`, the sudo key might be overridden via the environment
+        let spec = AstarChainSpecBuilder::default().build();
+        assert_eq!(
+            diff_genesis(&spec, &AstarChainSpecBuilder::default().build()),
+            Ok(vec![])
+        );
+
+        let changed = with_extra_endowed(
+            AstarChainSpecBuilder::default().build(),
+            vec![(alice.clone(), ASTR)],
+        )
+        .unwrap();
+        assert_eq!(
+            diff_genesis(&spec, &changed),
+            Ok(vec![FieldDiff::Balances {
+                only_in_a: vec![(alice.clone(), 1_000_000_000 * ASTR)],
+                only_in_b: vec![(alice, 1_000_000_001 * ASTR)],
+            }])
+        );
+
+        let mut dapp_staking = DappStakingParams::default();
+        dapp_staking.slots_per_tier = vec![20, 20, 30, 40];
+        let changed = AstarChainSpecBuilder::default()
+            .genesis(
+                AstarGenesisBuilder::default()
+                    .para_id(3000)
+                    .dapp_staking(dapp_staking),
+            )
+            .build();
+        assert_eq!(
+            diff_genesis(&spec, &changed),
+            Ok(vec![
+                FieldDiff::ParaId {
+                    a: ASTAR_PARA_ID,
+                    b: 3000
+                },
+                FieldDiff::DappStaking("slots_per_tier"),
+            ])
+        );
+    }
+
+    #[test]
+    fn evm_chain_id_is_fixed_by_runtime() {
+        assert_eq!(astar_runtime::ChainId::get(), 0x250);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&get_chain_spec().as_json(false).unwrap()).unwrap();
+        let runtime = json["genesis"]["runtime"]
+            .as_object()
+            .expect("spec holds a runtime genesis");
+        assert!(runtime.contains_key("evm"));
+        assert!(!runtime.contains_key("evmChainId"));
+    }
+
+    #[test]
+    fn inflation_presets_are_distinct_and_valid() {
+        let presets = [
+            InflationPreset::Mainnet,
+            InflationPreset::Conservative,
+            InflationPreset::Aggressive,
+        ];
+        for (index, preset) in presets.iter().enumerate() {
+            assert!(preset.params().is_valid(), "{:?} is invalid", preset);
+            for other in &presets[index + 1..] {
+                assert_ne!(preset.params(), other.params());
+            }
+        }
+        assert_eq!(
+            InflationPreset::Mainnet.params(),
+            InflationParameters::default()
+        );
+
+        let genesis = AstarGenesisBuilder::default()
+            .inflation_preset(InflationPreset::Conservative)
+            .build();
+        assert_eq!(
+            genesis.inflation.params,
+            InflationPreset::Conservative.params()
+        );
+    }
+
+    #[test]
+    fn inflation_params_are_applied() {
+        assert_eq!(
+            dev_genesis().inflation.params,
+            InflationParameters::default()
+        );
+
+        let params = InflationParameters {
+            max_inflation_rate: Perquintill::from_percent(5),
+            treasury_part: Perquintill::from_percent(10),
+            collators_part: Perquintill::from_percent(5),
+            dapps_part: Perquintill::from_percent(15),
+            base_stakers_part: Perquintill::from_percent(20),
+            adjustable_stakers_part: Perquintill::from_percent(40),
+            bonus_part: Perquintill::from_percent(10),
+            ideal_staking_rate: Perquintill::from_percent(60),
+        };
+        assert!(params.is_valid());
+        assert_eq!(resolve_inflation_params(Some(params), true), params);
+        assert_eq!(
+            resolve_inflation_params(None, true),
+            InflationParameters::default()
+        );
+
+        let spec = AstarChainSpecBuilder::default()
+            .chain_type(ChainType::Live)
+            .genesis(AstarGenesisBuilder::default().inflation_params(params))
+            .build();
+        let genesis = runtime_genesis(&spec).expect("spec holds a runtime genesis");
+        assert_eq!(genesis.inflation.params, params);
+    }
+
+    #[test]
+    fn inflation_params_are_loaded_from_json() {
+        let params = InflationParameters {
+            max_inflation_rate: Perquintill::from_percent(6),
+            treasury_part: Perquintill::from_percent(5),
+            collators_part: Perquintill::from_percent(5),
+            dapps_part: Perquintill::from_percent(20),
+            base_stakers_part: Perquintill::from_percent(30),
+            adjustable_stakers_part: Perquintill::from_percent(30),
+            bonus_part: Perquintill::from_percent(10),
+            ideal_staking_rate: Perquintill::from_percent(45),
+        };
+        let json = serde_json::to_vec(&params).unwrap();
+        assert_eq!(inflation_params_from_json(&json), Ok(params));
+
+        let genesis = AstarGenesisBuilder::default()
+            .inflation_params(inflation_params_from_json(&json).unwrap())
+            .build();
+        assert_eq!(genesis.inflation.params, params);
+
+        let mut invalid = serde_json::to_value(params).unwrap();
+        invalid["max_inflation_rate"] = (Perquintill::one().deconstruct() + 1).into();
+        assert_eq!(
+            inflation_params_from_json(invalid.to_string().as_bytes()),
+            Err("Inflation parameter max_inflation_rate exceeds 100%".into())
+        );
+
+        let mut invalid = serde_json::to_value(params).unwrap();
+        invalid["bonus_part"] = Perquintill::from_percent(11).deconstruct().into();
+        assert_eq!(
+            inflation_params_from_json(invalid.to_string().as_bytes()),
+            Err("Sum of inflation parts must be exactly 100%".into())
+        );
+    }
+
+    #[test]
+    fn local_chain_spec_is_built() {
+        let spec = get_local_chain_spec();
+        assert_eq!(spec.id(), "astar-local");
+        assert_eq!(spec.chain_type(), ChainType::Local);
+
+        let genesis = runtime_genesis(&spec).expect("spec holds a runtime genesis");
+        let collators: Vec<_> = ["Alice", "Bob", "Charlie", "Dave"]
+            .into_iter()
+            .map(get_account_id_from_seed::<sr25519::Public>)
+            .collect();
+        assert_eq!(genesis.collator_selection.invulnerables, collators);
+        assert_eq!(genesis.session.keys.len(), 4);
+        assert_eq!(genesis.balances.balances.len(), 6);
+
+        assert!(spec.build_storage().is_ok());
+    }
+
+    #[test]
+    fn genesis_code_is_identical_across_specs() {
+        let code = |spec: AstarChainSpec| {
+            runtime_genesis(&spec)
+                .expect("spec holds a runtime genesis")
+                .system
+                .code
+        };
+
+        let dev = code(get_chain_spec());
+        assert_eq!(dev, wasm_binary_unwrap());
+        assert_eq!(dev, code(get_local_chain_spec()));
+    }
+
+    #[test]
+    fn accounts_are_decoded_from_ss58() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let astar_prefix: u16 = SS58Prefix::get().into();
+
+        let astar_address = alice.to_ss58check_with_version(astar_prefix.into());
+        assert_eq!(
+            account_from_ss58(&astar_address, Some(astar_prefix)),
+            Ok(alice.clone())
+        );
+        assert_eq!(
+            account_from_ss58(&astar_address, Some(2)),
+            Err(ChainSpecError::InvalidAccount(format!(
+                "Invalid SS58 address `{}` is encoded with prefix {} rather than 2",
+                astar_address, astar_prefix
+            )))
+        );
+
+        let substrate_address = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+        assert_eq!(
+            account_from_ss58(substrate_address, None),
+            Ok(alice.clone())
+        );
+        assert_eq!(
+            account_from_ss58(substrate_address, Some(astar_prefix)),
+            load_account(substrate_address, Some(astar_prefix), false)
+        );
+        assert_eq!(account_from_ss58(substrate_address, Some(42)), Ok(alice));
+
+        let corrupted = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ";
+        assert_eq!(
+            account_from_ss58(corrupted, None),
+            Err(ChainSpecError::InvalidAccount(format!(
+                "Invalid SS58 address `{}`: {:?}",
+                corrupted,
+                PublicError::InvalidChecksum
+            )))
+        );
+    }
+
+    #[test]
+    fn loaded_accounts_are_checked_against_ss58_prefix() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let astar_prefix: u16 = SS58Prefix::get().into();
+        let address = |prefix: u16| alice.to_ss58check_with_version(prefix.into());
+
+        // Matching
+        assert_eq!(
+            load_account(&address(astar_prefix), Some(astar_prefix), true),
+            Ok(alice.clone())
+        );
+
+        // Mismatching, e.g. a Kusama address
+        assert_eq!(
+            load_account(&address(2), Some(astar_prefix), false),
+            Ok(alice.clone())
+        );
+        assert_eq!(
+            load_account(&address(2), Some(astar_prefix), true),
+            Err(ChainSpecError::InvalidAccount(format!(
+                "Invalid SS58 address `{}` is encoded with prefix 2 rather than {}",
+                address(2),
+                astar_prefix
+            )))
+        );
+        assert_eq!(load_account(&address(2), None, true), Ok(alice.clone()));
+
+        // Generic
+        assert_eq!(
+            load_account(&address(GENERIC_SS58_PREFIX), Some(astar_prefix), true),
+            Ok(alice)
+        );
+    }
+
+    #[test]
+    fn invulnerables_must_cover_candidacy_bond() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let collators = vec![collator_keys(authority_keys_from_seed("Alice"))];
+        let params = CollatorSelectionParams::default();
+
+        assert_eq!(
+            check_invulnerables_funded(&default_endowment(), &collators, &params),
+            Ok(())
+        );
+        assert_eq!(
+            check_invulnerables_funded(
+                &[(alice.clone(), params.candidacy_bond)],
+                &collators,
+                &params
+            ),
+            Ok(())
+        );
+
+        let error = check_invulnerables_funded(
+            &[(alice.clone(), params.candidacy_bond - 1)],
+            &collators,
+            &params,
+        )
+        .unwrap_err();
+        assert!(error.starts_with(&format!("Invulnerable {} is endowed", alice)));
+        assert!(check_invulnerables_funded(&[], &collators, &params).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid collator selection genesis configuration")]
+    fn under_funded_invulnerables_are_rejected() {
+        // Dave isn't endowed in the dev chain specification
+        AstarGenesisBuilder::default()
+            .authorities(vec![
+                authority_keys_from_seed("Alice"),
+                authority_keys_from_seed("Dave"),
+            ])
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid collator selection genesis configuration")]
+    fn invalid_genesis_panics_when_the_spec_is_built() {
+        // Without building the genesis storage
+        AstarChainSpecBuilder::default()
+            .genesis(AstarGenesisBuilder::default().authorities(vec![
+                authority_keys_from_seed("Alice"),
+                authority_keys_from_seed("Dave"),
+            ]))
+            .build();
+    }
+
+    #[test]
+    fn vesting_schedules_are_seeded() {
+        assert!(dev_genesis().vesting.vesting.is_empty());
+
+        let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+        let mut balances = default_endowment();
+        balances.push((charlie.clone(), 1_000 * ASTR));
+        let schedule = vec![(charlie.clone(), 10, 100, 100 * ASTR)];
+
+        let spec = AstarChainSpecBuilder::default()
+            .genesis(
+                AstarGenesisBuilder::default()
+                    .balances(balances.clone())
+                    .vesting(schedule.clone()),
+            )
+            .build();
+        let genesis = runtime_genesis(&spec).expect("spec holds a runtime genesis");
+        assert_eq!(genesis.vesting.vesting, schedule);
+        assert!(spec.build_storage().is_ok());
+
+        assert_eq!(check_vesting_endowed(&balances, &schedule), Ok(()));
+        assert_eq!(
+            check_vesting_endowed(&default_endowment(), &schedule),
+            Err(format!("Vesting account {} isn't endowed", charlie))
+        );
+        assert!(check_vesting_endowed(&[(charlie, 10 * ASTR)], &schedule).is_err());
+    }
+
+    #[test]
+    fn sudo_can_be_vested() {
+        let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+        let schedule = (charlie.clone(), 10, 100, 100 * ASTR);
+
+        let spec = AstarChainSpecBuilder::default()
+            .genesis(AstarGenesisBuilder::default().vested_sudo(schedule.clone(), 1_000 * ASTR))
+            .build();
+        let genesis = runtime_genesis(&spec).expect("spec holds a runtime genesis");
+        assert_eq!(genesis.sudo.key, Some(charlie.clone()));
+        assert!(genesis
+            .balances
+            .balances
+            .contains(&(charlie.clone(), 1_000 * ASTR)));
+        assert_eq!(genesis.vesting.vesting, vec![schedule.clone()]);
+        assert!(spec.build_storage().is_ok());
+
+        assert_eq!(
+            AstarGenesisBuilder::default()
+                .vested_sudo(schedule, 10 * ASTR)
+                .validate(),
+            Err(format!(
+                "Invalid vesting genesis configuration: Vesting account {} is endowed with {}, less than its liquid amount {}",
+                charlie,
+                10 * ASTR,
+                100 * ASTR
+            ))
+        );
+    }
+
+    /// `spec_version` of the Astar runtime.
+    ///
+    /// Bump it together with `VERSION.spec_version` in `runtime/astar`, a mismatch means the
+    /// genesis code was built from a stale runtime.
+    const EXPECTED_SPEC_VERSION: u32 = 80;
+
+    #[test]
+    fn genesis_code_matches_runtime_version() {
+        use sc_executor::{RuntimeVersion, WasmExecutor};
+        use sp_core::traits::ReadRuntimeVersion;
+
+        let code = runtime_genesis(&get_chain_spec())
+            .expect("spec holds a runtime genesis")
+            .system
+            .code;
+        let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder().build();
+        let mut ext = sp_io::TestExternalities::default();
+        let encoded = executor
+            .read_runtime_version(&code, &mut ext.ext())
+            .expect("genesis code embeds its runtime version");
+        let version = RuntimeVersion::decode(&mut &encoded[..]).expect("version is SCALE encoded");
+
+        assert_eq!(version.spec_name, astar_runtime::VERSION.spec_name);
+        assert_eq!(version.spec_version, astar_runtime::VERSION.spec_version);
+        assert_eq!(version.spec_version, EXPECTED_SPEC_VERSION);
+    }
+
+    #[test]
+    fn assets_are_registered() {
+        assert!(dev_genesis().assets.assets.is_empty());
+
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let asset = AssetGenesisEntry {
+            id: 1,
+            admin: alice.clone(),
+            is_sufficient: true,
+            min_balance: 1_000,
+            name: b"Test Asset".to_vec(),
+            symbol: b"TST".to_vec(),
+            decimals: 12,
+        };
+        let spec = AstarChainSpecBuilder::default()
+            .genesis(AstarGenesisBuilder::default().assets(vec![asset.clone()]))
+            .build();
+        let config = runtime_genesis(&spec)
+            .expect("spec holds a runtime genesis")
+            .assets;
+        assert_eq!(config.assets, vec![(1, alice, true, 1_000)]);
+        assert_eq!(
+            config.metadata,
+            vec![(1, b"Test Asset".to_vec(), b"TST".to_vec(), 12)]
+        );
+        assert!(spec.build_storage().is_ok());
+
+        assert_eq!(
+            check_assets(&[asset.clone(), asset.clone()]),
+            Err("Duplicate asset id 1".into())
+        );
+        assert!(check_assets(&[AssetGenesisEntry {
+            min_balance: 0,
+            ..asset
+        }])
+        .is_err());
+    }
+
+    #[test]
+    fn genesis_allocation_is_sorted_and_totaled() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+
+        let (balances, total) = genesis_allocation(&get_chain_spec()).unwrap();
+        assert_eq!(
+            balances,
+            vec![
+                (alice.clone(), 1_000_000_000 * ASTR),
+                (bob.clone(), 1_000_000_000 * ASTR)
+            ]
+        );
+        assert_eq!(total, 2_000_000_000 * ASTR);
+
+        let spec = with_extra_endowed(get_chain_spec(), vec![(bob.clone(), ASTR)]).unwrap();
+        let (balances, _) = genesis_allocation(&spec).unwrap();
+        assert_eq!(balances[0], (bob, 1_000_000_001 * ASTR));
+    }
+
+    #[test]
+    fn safe_xcm_version_is_set() {
+        assert_eq!(dev_genesis().polkadot_xcm.safe_xcm_version, None);
+
+        let genesis = AstarGenesisBuilder::default().xcm_version(3).build();
+        assert_eq!(genesis.polkadot_xcm.safe_xcm_version, Some(3));
+    }
+
+    #[test]
+    fn spec_hash_is_stable() {
+        let spec = AstarChainSpecBuilder::default().build();
+        let hash = spec_blake2_256(&spec).unwrap();
+        assert_eq!(hash, spec_blake2_256(&spec).unwrap());
+        assert_eq!(
+            hash,
+            spec_blake2_256(&AstarChainSpecBuilder::default().build()).unwrap()
+        );
+
+        let other = AstarChainSpecBuilder::default()
+            .genesis(AstarGenesisBuilder::default().para_id(3000))
+            .build();
+        assert_ne!(hash, spec_blake2_256(&other).unwrap());
+    }
+
+    #[test]
+    fn genesis_json_is_sorted() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        assert!(bob < alice);
+
+        let spec = AstarChainSpecBuilder::default()
+            .genesis(AstarGenesisBuilder::default().balances(vec![
+                (alice.clone(), 1_000 * ASTR),
+                (bob.clone(), 1_000 * ASTR),
+            ]))
+            .build();
+        let pretty = genesis_json_pretty(&spec).unwrap();
+        assert!(pretty.contains("\n  \"balances\": {"));
+
+        let json: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        let accounts = json["balances"]["balances"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry[0].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(accounts, vec![bob.to_ss58check(), alice.to_ss58check()]);
+
+        let keys = json.as_object().unwrap().keys().collect::<Vec<_>>();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn genesis_state_root_is_deterministic() {
+        let spec = AstarChainSpecBuilder::default().build();
+        let root = genesis_state_root(&spec).unwrap();
+        assert_eq!(
+            root,
+            genesis_state_root(&AstarChainSpecBuilder::default().build()).unwrap()
+        );
+
+        let head = genesis_head(&spec).unwrap();
+        let header = astar_primitives::Header::decode(&mut &head[..]).unwrap();
+        assert_eq!(header.state_root, root);
+        assert_eq!(header.number, 0);
+    }
+
+    #[test]
+    fn genesis_accounts_fixture_is_parsed() {
+        let bytes = std::fs::read(GENESIS_ACCOUNTS_FIXTURE).unwrap();
+        let (balances, sudo_key, vesting) = genesis_accounts_from_json(&bytes).unwrap();
+
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+        assert_eq!(
+            balances,
+            vec![
+                (alice.clone(), 1_000_000_000 * ASTR),
+                (bob.clone(), 1_000_000_000 * ASTR),
+                (charlie.clone(), 1_000 * ASTR),
+            ]
+        );
+        assert_eq!(sudo_key, alice);
+        assert_eq!(vesting, vec![(charlie, 0, 100, 100 * ASTR)]);
+    }
+
+    #[test]
+    fn balances_snapshot_fixture_is_rescaled() {
+        let balances = balances_from_snapshot(Path::new(BALANCES_SNAPSHOT_FIXTURE)).unwrap();
+
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        assert_eq!(
+            balances,
+            vec![(alice.clone(), 1_000 * ASTR), (bob, 5 * ASTR / 10)]
+        );
+
+        // Dust below ASTR precision is rounded down.
+        let balances = snapshot_balances_from_json(
+            br#"{ "decimals": 20, "balances": [["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", 1099]] }"#,
+        )
+        .unwrap();
+        assert_eq!(balances, vec![(alice, 10)]);
+    }
+
+    #[test]
+    fn invalid_balances_snapshots_are_rejected() {
+        let overflow = snapshot_balances_from_json(
+            br#"{ "decimals": 0, "balances": [["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", 1000000000000000000000000]] }"#,
+        );
+        assert!(matches!(overflow, Err(ChainSpecError::InvalidFile(_))));
+
+        let decimals = snapshot_balances_from_json(br#"{ "decimals": 100, "balances": [] }"#);
+        assert!(matches!(decimals, Err(ChainSpecError::InvalidFile(_))));
+
+        let account =
+            snapshot_balances_from_json(br#"{ "decimals": 12, "balances": [["alice", 1]] }"#);
+        assert!(matches!(account, Err(ChainSpecError::InvalidAccount(_))));
+    }
+
+    #[test]
+    fn balances_are_rescaled() {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+
+        let up = rescale_balances(vec![(alice.clone(), 1_234_567)], 12, 18).unwrap();
+        assert_eq!(up, vec![(alice.clone(), 1_234_567_000_000)]);
+        // Scaling up loses no precision.
+        assert_eq!(
+            rescale_balances(up, 18, 12).unwrap(),
+            vec![(alice.clone(), 1_234_567)]
+        );
+
+        let down = rescale_balances(vec![(alice.clone(), 1_999_999)], 18, 12).unwrap();
+        assert_eq!(down, vec![(alice.clone(), 1)]);
+
+        assert_eq!(
+            rescale_balances(vec![(alice.clone(), 5)], 18, 18).unwrap(),
+            vec![(alice.clone(), 5)]
+        );
+
+        let overflow = rescale_balances(vec![(alice.clone(), Balance::MAX / 10)], 0, 18);
+        assert_eq!(
+            overflow,
+            Err(format!("Balance of {} overflows once rescaled", alice))
+        );
+        assert!(rescale_balances(vec![], 0, 100).is_err());
+    }
+
+    /// Serve `response` once on a local port, returning the URL to fetch it from.
+    #[cfg(feature = "remote-spec")]
+    fn serve_once(response: Vec<u8>) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/balances.json", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request);
+            let _ = stream.write_all(&response);
+        });
+        url
+    }
+
+    #[cfg(feature = "remote-spec")]
+    #[test]
+    fn balances_are_fetched_from_url() {
+        let mut response = b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n".to_vec();
+        response.extend(std::fs::read(BALANCES_SNAPSHOT_FIXTURE).unwrap());
+        assert_eq!(
+            balances_from_url(&serve_once(response)).unwrap(),
+            balances_from_snapshot(Path::new(BALANCES_SNAPSHOT_FIXTURE)).unwrap()
+        );
+
+        let not_found = serve_once(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_vec());
+        assert!(matches!(
+            balances_from_url(&not_found),
+            Err(ChainSpecError::InvalidFile(_))
+        ));
+    }
+
+    #[cfg(feature = "remote-spec")]
+    #[test]
+    fn remote_responses_are_bounded() {
+        // Announced length
+        let announced = serve_once(
+            [
+                b"HTTP/1.1 200 OK\r\nContent-Length: 2048\r\n\r\n".as_slice(),
+                &[b' '; 2048],
+            ]
+            .concat(),
+        );
+        assert_eq!(
+            http_get(&announced, 1024, REMOTE_SPEC_TIMEOUT).unwrap_err(),
+            "Response exceeds 1024 bytes"
+        );
+
+        // Streamed until the connection closes
+        let streamed = serve_once(
+            [
+                b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n".as_slice(),
+                &[b' '; 2048],
+            ]
+            .concat(),
+        );
+        assert_eq!(
+            http_get(&streamed, 1024, REMOTE_SPEC_TIMEOUT).unwrap_err(),
+            "Response exceeds 1024 bytes"
+        );
+
+        // A server which never responds
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/balances.json", listener.local_addr().unwrap());
+        let start = std::time::Instant::now();
+        assert!(http_get(&url, 1024, std::time::Duration::from_millis(200)).is_err());
+        assert!(start.elapsed() < REMOTE_SPEC_TIMEOUT);
+        drop(listener);
+    }
+
+    #[test]
+    fn spec_from_toml_fixture_builds() {
+        let spec = spec_from_toml(Path::new(TOML_SPEC_FIXTURE)).unwrap();
+        assert_eq!(spec.name(), "Astar TOML Testnet");
+        assert_eq!(spec.id(), "astar-toml");
+        assert_eq!(spec.chain_type(), ChainType::Local);
+        assert_eq!(spec.extensions().para_id, 3000);
+        assert_eq!(spec.extensions().relay_chain, "rococo-local");
+        assert!(spec.build_storage().is_ok());
+
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let genesis = runtime_genesis(&spec).expect("spec holds a runtime genesis");
+        assert_eq!(genesis.sudo.key, Some(bob.clone()));
+        assert_eq!(
+            genesis.balances.balances,
+            vec![
+                (alice.clone(), 10_000_000 * ASTR),
+                (bob.clone(), 10_000_000 * ASTR + 5 * ASTR / 10),
+            ]
+        );
+        assert_eq!(genesis.collator_selection.invulnerables, vec![alice, bob]);
+        assert_eq!(genesis.dapp_staking.slots_per_tier, vec![5, 10, 15, 20]);
+    }
+
+    #[test]
+    fn spec_toml_template_builds() {
+        let spec = spec_builder_from_toml(SPEC_TOML_TEMPLATE)
+            .unwrap()
+            .try_build()
+            .unwrap();
+        assert_eq!(spec.id(), "astar-testnet");
+        assert!(spec.build_storage().is_ok());
+    }
+
+    #[test]
+    fn invalid_toml_specs_are_rejected() {
+        assert!(matches!(
+            spec_builder_from_toml("[network]\nunknown = 1"),
+            Err(ChainSpecError::InvalidFile(_))
+        ));
+        assert!(matches!(
+            spec_builder_from_toml("[sudo]\naccount = \"alice\""),
+            Err(ChainSpecError::InvalidAccount(_))
+        ));
+        assert!(matches!(
+            spec_builder_from_toml(
+                "[[balances]]\naccount = \"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY\"\namount = \"1.2.3\""
+            ),
+            Err(ChainSpecError::InvalidFile(_))
+        ));
+        assert!(matches!(
+            spec_from_toml(Path::new("does-not-exist.toml")),
+            Err(ChainSpecError::InvalidFile(_))
+        ));
+    }
+
+    #[test]
+    fn chain_spec_from_file_builds() {
+        get_chain_spec_from_file(Path::new(GENESIS_ACCOUNTS_FIXTURE))
+            .unwrap()
+            .build_storage()
+            .unwrap();
+    }
+
+    #[test]
+    fn malformed_genesis_accounts_are_rejected() {
+        // Not an SS58 address
+        let err = genesis_accounts_from_json(br#"{ "balances": [["alice", 1]], "sudo": "alice" }"#)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ChainSpecError::InvalidAccount(e) if e.contains("Invalid SS58 address `alice`")
+        ));
+
+        // Missing `sudo` entry
+        let err = genesis_accounts_from_json(br#"{ "balances": [] }"#).unwrap_err();
+        assert!(matches!(
+            err,
+            ChainSpecError::InvalidFile(e) if e.contains("missing field `sudo`")
+        ));
+
+        // Unknown entry
+        let err = genesis_accounts_from_json(
+            br#"{ "balances": [], "sudo": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", "foo": 1 }"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ChainSpecError::InvalidFile(e) if e.contains("unknown field `foo`")));
+    }
 }
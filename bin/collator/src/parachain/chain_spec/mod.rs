@@ -18,9 +18,91 @@
 
 use sc_chain_spec::ChainSpecExtension;
 use serde::{Deserialize, Serialize};
-use sp_core::{Pair, Public};
+use sp_core::{Pair, Public, H160};
+use sp_runtime::{
+    traits::{IdentifyAccount, Verify},
+    Permill,
+};
+use std::collections::BTreeMap;
 
-use astar_primitives::Block;
+use astar_primitives::{AccountId, Block, Signature};
+
+/// Genesis config of the given runtime, shared by the Astar, Shiden & Shibuya specifications so
+/// that their session keys, collator selection, EVM seeding & dApp staking don't drift.
+///
+/// The runtimes share their genesis construction but not their types, hence a macro. Each
+/// `(stash, controller, aura)` collator gets its session keys set through `session_keys`, and its
+/// stash is made invulnerable if `invulnerables` is set. The genesis fields a specification
+/// configures on its own, because only some runtimes have them or because they're built
+/// differently, e.g. Astar's assets, are given last.
+macro_rules! make_genesis {
+    (
+        $runtime:ident,
+        code: $code:expr,
+        root_key: $root_key:expr,
+        parachain_id: $parachain_id:expr,
+        balances: $balances:expr,
+        vesting: $vesting:expr,
+        collators: $collators:expr,
+        session_keys: $session_keys:expr,
+        invulnerables: $invulnerables:expr,
+        desired_candidates: $desired_candidates:expr,
+        candidacy_bond: $candidacy_bond:expr,
+        evm_accounts: $evm_accounts:expr,
+        dapp_staking: $dapp_staking:expr,
+        inflation_params: $inflation_params:expr,
+        $($field:ident: $value:expr),* $(,)?
+    ) => {{
+        let collators: Vec<(_, _, _)> = $collators;
+        let invulnerables = if $invulnerables {
+            collators.iter().map(|(stash, _, _)| stash.clone()).collect()
+        } else {
+            vec![]
+        };
+        let session_keys = $session_keys;
+
+        $runtime::GenesisConfig {
+            system: $runtime::SystemConfig { code: $code },
+            sudo: $runtime::SudoConfig { key: $root_key },
+            parachain_info: $runtime::ParachainInfoConfig {
+                parachain_id: $parachain_id,
+            },
+            balances: $runtime::BalancesConfig {
+                balances: $balances,
+            },
+            vesting: $runtime::VestingConfig { vesting: $vesting },
+            session: $runtime::SessionConfig {
+                keys: collators
+                    .into_iter()
+                    .map(|(stash, controller, aura)| (controller, stash, session_keys(aura)))
+                    .collect(),
+            },
+            // Aura authorities are initialized by the session pallet from the collators' session
+            // keys, setting them here as well would make the genesis build panic.
+            aura: $runtime::AuraConfig {
+                authorities: vec![],
+            },
+            aura_ext: Default::default(),
+            collator_selection: $runtime::CollatorSelectionConfig {
+                desired_candidates: $desired_candidates,
+                candidacy_bond: $candidacy_bond,
+                invulnerables,
+            },
+            evm: $runtime::EVMConfig {
+                accounts: $evm_accounts,
+            },
+            ethereum: Default::default(),
+            // The parachain system genesis config has no fields, validation data is only ever
+            // provided through the `set_validation_data` inherent.
+            parachain_system: Default::default(),
+            dapp_staking: $dapp_staking,
+            inflation: $runtime::InflationConfig {
+                params: $inflation_params,
+            },
+            $($field: $value,)*
+        }
+    }};
+}
 
 pub mod astar;
 pub mod shibuya;
@@ -34,7 +116,7 @@ pub use shiden::ShidenChainSpec;
 ///
 /// Additional parameters for some Substrate core modules,
 /// customizable from the chain spec.
-#[derive(Default, Clone, Serialize, Deserialize, ChainSpecExtension)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize, ChainSpecExtension)]
 #[serde(rename_all = "camelCase")]
 pub struct Extensions {
     /// Known bad block hashes.
@@ -43,6 +125,16 @@ pub struct Extensions {
     pub relay_chain: String,
     /// The id of the Parachain.
     pub para_id: u32,
+    /// Hard fork the spec belongs to, for coordinating consensus breaking upgrades.
+    ///
+    /// Serialized as `hardForkId` since the spec's own `forkId` shares the same JSON object, and
+    /// omitted when unset so that existing specs are unchanged.
+    #[serde(
+        default,
+        rename = "hardForkId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fork_id: Option<String>,
 }
 
 impl Extensions {
@@ -52,9 +144,278 @@ impl Extensions {
     }
 }
 
+/// Relay chains a parachain can connect to, as named in [`Extensions::relay_chain`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RelayChain {
+    /// Polkadot.
+    Polkadot,
+    /// Rococo.
+    Rococo,
+    /// Tokyo, the Astar test relay chain.
+    Tokyo,
+    /// Any other relay chain, e.g. a local one.
+    Custom(String),
+}
+
+impl RelayChain {
+    /// Name of the relay chain, as used by `--chain`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Polkadot => "polkadot",
+            Self::Rococo => "rococo",
+            Self::Tokyo => "tokyo",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl From<RelayChain> for String {
+    fn from(relay_chain: RelayChain) -> Self {
+        relay_chain.as_str().into()
+    }
+}
+
+/// The simplest EVM bytecode which reverts without returning any data,
+/// `PUSH1 0x00 PUSH1 0x00 REVERT`, i.e. `revert(offset = 0, size = 0)`.
+///
+/// It's pre-deployed under all the precompile addresses to ensure they can be called from
+/// within contracts, since the EVM only calls addresses which hold _some_ code.
+pub const EVM_REVERT_BYTECODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xFD];
+
+/// Whether `code` is exactly the `PUSH1 0x00 PUSH1 0x00 REVERT` stub, see [`EVM_REVERT_BYTECODE`].
+///
+/// Any other code under a precompile address could be executed as a regular contract.
+pub fn is_revert_stub(code: &[u8]) -> bool {
+    const PUSH1: u8 = 0x60;
+    const REVERT: u8 = 0xFD;
+
+    code == [PUSH1, 0x00, PUSH1, 0x00, REVERT]
+}
+
 /// Helper function to generate a crypto pair from seed
 fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
     TPublic::Pair::from_string(&format!("//{}", seed), None)
         .expect("static values are valid; qed")
         .public()
 }
+
+/// EVM genesis accounts holding the revert bytecode at each of the given precompile addresses.
+fn precompile_revert_accounts(
+    addresses: impl Iterator<Item = H160>,
+) -> BTreeMap<H160, fp_evm::GenesisAccount> {
+    debug_assert!(is_revert_stub(&EVM_REVERT_BYTECODE));
+
+    // We need _some_ code inserted at the precompile address so that
+    // the evm will actually call the address.
+    addresses
+        .map(|addr| {
+            (
+                addr,
+                fp_evm::GenesisAccount {
+                    nonce: Default::default(),
+                    balance: Default::default(),
+                    storage: Default::default(),
+                    code: EVM_REVERT_BYTECODE.to_vec(),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Share of the dApp staking rewards of each tier, the same on all the chains.
+fn dapp_staking_reward_portion() -> Vec<Permill> {
+    [40, 30, 20, 10].map(Permill::from_percent).to_vec()
+}
+
+/// Share of the dApp staking slots of each tier, the same on all the chains.
+fn dapp_staking_slot_distribution() -> Vec<Permill> {
+    [10, 20, 30, 40].map(Permill::from_percent).to_vec()
+}
+
+/// Number of dApp staking slots of each tier, the same on all the chains.
+fn dapp_staking_slots_per_tier() -> Vec<u16> {
+    vec![10, 20, 30, 40]
+}
+
+/// `Alice` & `Bob` as the `(stash, controller, aura)` collators of a dev chain, see
+/// [`make_genesis`].
+fn dev_collators<TPublic: Public>() -> Vec<(AccountId, AccountId, <TPublic::Pair as Pair>::Public)>
+{
+    ["Alice", "Bob"]
+        .into_iter()
+        .map(|seed| {
+            let account = get_account_id_from_seed::<sp_core::sr25519::Public>(seed);
+            (account.clone(), account, get_from_seed::<TPublic>(seed))
+        })
+        .collect()
+}
+
+/// Check the JSON of a chain spec against its fixture, `res/genesis/<id>.json`, pinning the genesis
+/// built by [`make_genesis`].
+///
+/// The runtime `code` is replaced with a placeholder, since it changes with every runtime
+/// upgrade. A missing fixture is written & the check fails, so that it gets reviewed & committed.
+/// Set `BLESS_GENESIS_FIXTURES` to rewrite the fixtures after an intended genesis change.
+#[cfg(test)]
+fn assert_matches_genesis_fixture(spec: &dyn sc_service::ChainSpec, code: &[u8]) {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("res/genesis")
+        .join(format!("{}.json", spec.id()));
+    let json = spec
+        .as_json(false)
+        .expect("spec can be serialized")
+        .replace(&sp_core::bytes::to_hex(code, false), "<runtime code>");
+
+    let bless = std::env::var_os("BLESS_GENESIS_FIXTURES").is_some();
+    match std::fs::read_to_string(&path) {
+        Ok(fixture) if !bless => assert!(
+            fixture == json,
+            "Spec `{}` differs from {}, set BLESS_GENESIS_FIXTURES if the change is intended",
+            spec.id(),
+            path.display()
+        ),
+        _ => {
+            std::fs::create_dir_all(path.parent().expect("fixtures have a directory"))
+                .and_then(|_| std::fs::write(&path, &json))
+                .expect("fixture can be written");
+            assert!(
+                bless,
+                "Missing {} written, review & commit it",
+                path.display()
+            );
+        }
+    }
+}
+
+type AccountPublic = <Signature as Verify>::Signer;
+
+/// Helper function to generate an account ID from seed
+fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
+where
+    AccountPublic: From<<TPublic::Pair as Pair>::Public>,
+{
+    AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relay_chains_are_named() {
+        assert_eq!(RelayChain::Polkadot.as_str(), "polkadot");
+        assert_eq!(RelayChain::Rococo.as_str(), "rococo");
+        assert_eq!(RelayChain::Tokyo.as_str(), "tokyo");
+        assert_eq!(
+            RelayChain::Custom("rococo-local".into()).as_str(),
+            "rococo-local"
+        );
+        assert_eq!(String::from(RelayChain::Tokyo), "tokyo");
+    }
+
+    #[test]
+    fn dev_accounts_match_well_known_keys() {
+        let account = |hex: &str| {
+            AccountId::from(
+                <[u8; 32]>::try_from(&sp_core::bytes::from_hex(hex).unwrap()[..]).unwrap(),
+            )
+        };
+        assert_eq!(
+            get_account_id_from_seed::<sp_core::sr25519::Public>("Alice"),
+            account("0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+        );
+        assert_eq!(
+            get_account_id_from_seed::<sp_core::sr25519::Public>("Bob"),
+            account("0x8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48")
+        );
+    }
+
+    #[test]
+    fn extensions_round_trip_through_json() {
+        let extensions = Extensions {
+            bad_blocks: Some([sp_core::H256::repeat_byte(0x01)].into_iter().collect()),
+            relay_chain: "tokyo".into(),
+            para_id: 2006,
+            fork_id: Some("astar-fork".into()),
+        };
+        let json = serde_json::to_string(&extensions).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Extensions>(&json).unwrap(),
+            extensions
+        );
+    }
+
+    #[test]
+    fn extensions_without_optional_fields_are_loaded() {
+        let extensions: Extensions =
+            serde_json::from_str(r#"{ "relayChain": "tokyo", "paraId": 2006 }"#).unwrap();
+        assert_eq!(
+            extensions,
+            Extensions {
+                bad_blocks: None,
+                relay_chain: "tokyo".into(),
+                para_id: 2006,
+                fork_id: None,
+            }
+        );
+
+        // Fields added by newer versions are ignored.
+        let extensions: Extensions =
+            serde_json::from_str(r#"{ "relayChain": "tokyo", "paraId": 2006, "forkId": "fork" }"#)
+                .unwrap();
+        assert_eq!(extensions.para_id, 2006);
+    }
+
+    #[test]
+    fn extensions_without_fork_id_serialize_unchanged() {
+        let mut extensions = Extensions {
+            bad_blocks: None,
+            relay_chain: "tokyo".into(),
+            para_id: 2006,
+            fork_id: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&extensions).unwrap(),
+            r#"{"badBlocks":null,"relayChain":"tokyo","paraId":2006}"#
+        );
+
+        extensions.fork_id = Some("astar-fork".into());
+        let json = serde_json::to_string(&extensions).unwrap();
+        assert_eq!(
+            json,
+            r#"{"badBlocks":null,"relayChain":"tokyo","paraId":2006,"hardForkId":"astar-fork"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Extensions>(&json).unwrap(),
+            extensions
+        );
+    }
+
+    #[test]
+    fn evm_revert_bytecode_decodes_to_revert() {
+        const PUSH1: u8 = 0x60;
+        const REVERT: u8 = 0xFD;
+
+        assert_eq!(EVM_REVERT_BYTECODE, [PUSH1, 0x00, PUSH1, 0x00, REVERT]);
+    }
+
+    #[test]
+    fn precompiles_are_seeded_with_revert_bytecode() {
+        let addresses = vec![H160::from_low_u64_be(1), H160::from_low_u64_be(20481)];
+        let accounts = precompile_revert_accounts(addresses.clone().into_iter());
+        assert_eq!(accounts.keys().cloned().collect::<Vec<_>>(), addresses);
+        assert!(accounts
+            .values()
+            .all(|account| account.code == EVM_REVERT_BYTECODE));
+    }
+
+    #[test]
+    fn revert_stub_is_recognized() {
+        assert!(is_revert_stub(&EVM_REVERT_BYTECODE));
+
+        // `RETURN` instead of `REVERT`.
+        assert!(!is_revert_stub(&[0x60, 0x00, 0x60, 0x00, 0xF3]));
+        assert!(!is_revert_stub(&EVM_REVERT_BYTECODE[..4]));
+        assert!(!is_revert_stub(&[]));
+    }
+}
@@ -21,21 +21,17 @@
 use cumulus_primitives_core::ParaId;
 use sc_service::ChainType;
 use shibuya_runtime::{
-    wasm_binary_unwrap, AccountId, AuraConfig, AuraId, Balance, BalancesConfig,
-    CollatorSelectionConfig, CouncilConfig, DappStakingConfig, DemocracyConfig, EVMChainIdConfig,
-    EVMConfig, GenesisConfig, InflationConfig, InflationParameters, ParachainInfoConfig,
-    Precompiles, SessionConfig, SessionKeys, Signature, SudoConfig, SystemConfig,
-    TechnicalCommitteeConfig, TierThreshold, TreasuryConfig, VestingConfig, SBY,
+    wasm_binary_unwrap, AccountId, AuraId, Balance, CouncilConfig, DappStakingConfig,
+    DemocracyConfig, EVMChainIdConfig, GenesisConfig, InflationParameters, Precompiles,
+    SessionKeys, TechnicalCommitteeConfig, TierThreshold, TreasuryConfig, SBY,
 };
-use sp_core::{sr25519, Pair, Public};
+use sp_core::sr25519;
 
-use sp_runtime::{
-    traits::{IdentifyAccount, Verify},
-    Permill,
+use super::{
+    dapp_staking_reward_portion, dapp_staking_slot_distribution, dapp_staking_slots_per_tier,
+    dev_collators, get_account_id_from_seed, precompile_revert_accounts, Extensions, RelayChain,
 };
 
-use super::{get_from_seed, Extensions};
-
 const PARA_ID: u32 = 1000;
 
 /// Specialized `ChainSpec` for Shibuya testnet.
@@ -72,110 +68,35 @@ pub fn get_chain_spec() -> ShibuyaChainSpec {
         Some(properties),
         Extensions {
             bad_blocks: Default::default(),
-            relay_chain: "tokyo".into(),
+            relay_chain: RelayChain::Tokyo.into(),
             para_id: PARA_ID,
+            fork_id: None,
         },
     )
 }
 
-fn session_keys(aura: AuraId) -> SessionKeys {
-    SessionKeys { aura }
-}
-
 /// Helper function to create Shibuya GenesisConfig.
 fn make_genesis(
     balances: Vec<(AccountId, Balance)>,
     root_key: AccountId,
     parachain_id: ParaId,
 ) -> GenesisConfig {
-    let authorities = vec![
-        (
-            get_account_id_from_seed::<sr25519::Public>("Alice"),
-            get_from_seed::<AuraId>("Alice"),
-        ),
-        (
-            get_account_id_from_seed::<sr25519::Public>("Bob"),
-            get_from_seed::<AuraId>("Bob"),
-        ),
-    ];
-
-    // This is supposed the be the simplest bytecode to revert without returning any data.
-    // We will pre-deploy it under all of our precompiles to ensure they can be called from
-    // within contracts.
-    // (PUSH1 0x00 PUSH1 0x00 REVERT)
-    let revert_bytecode = vec![0x60, 0x00, 0x60, 0x00, 0xFD];
-
-    GenesisConfig {
-        system: SystemConfig {
-            code: wasm_binary_unwrap().to_vec(),
-        },
-        sudo: SudoConfig {
-            key: Some(root_key),
-        },
-        parachain_info: ParachainInfoConfig { parachain_id },
-        balances: BalancesConfig { balances },
-        vesting: VestingConfig { vesting: vec![] },
-        session: SessionConfig {
-            keys: authorities
-                .iter()
-                .map(|x| (x.0.clone(), x.0.clone(), session_keys(x.1.clone())))
-                .collect::<Vec<_>>(),
-        },
-        aura: AuraConfig {
-            authorities: vec![],
-        },
-        aura_ext: Default::default(),
-        collator_selection: CollatorSelectionConfig {
-            desired_candidates: 32,
-            candidacy_bond: 32_000 * SBY,
-            invulnerables: authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
-        },
-        evm: EVMConfig {
-            // We need _some_ code inserted at the precompile address so that
-            // the evm will actually call the address.
-            accounts: Precompiles::used_addresses()
-                .map(|addr| {
-                    (
-                        addr,
-                        fp_evm::GenesisAccount {
-                            nonce: Default::default(),
-                            balance: Default::default(),
-                            storage: Default::default(),
-                            code: revert_bytecode.clone(),
-                        },
-                    )
-                })
-                .collect(),
-        },
-        evm_chain_id: EVMChainIdConfig { chain_id: 0x51 },
-        ethereum: Default::default(),
-        polkadot_xcm: Default::default(),
-        assets: Default::default(),
-        parachain_system: Default::default(),
-        transaction_payment: Default::default(),
-        council: CouncilConfig {
-            members: vec![],
-            phantom: Default::default(),
-        },
-        technical_committee: TechnicalCommitteeConfig {
-            members: vec![],
-            phantom: Default::default(),
-        },
-        democracy: DemocracyConfig::default(),
-        treasury: TreasuryConfig::default(),
+    make_genesis!(
+        shibuya_runtime,
+        code: wasm_binary_unwrap().to_vec(),
+        root_key: Some(root_key),
+        parachain_id: parachain_id,
+        balances: balances,
+        vesting: vec![],
+        collators: dev_collators::<AuraId>(),
+        session_keys: |aura| SessionKeys { aura },
+        invulnerables: true,
+        desired_candidates: 32,
+        candidacy_bond: 32_000 * SBY,
+        evm_accounts: precompile_revert_accounts(Precompiles::used_addresses()),
         dapp_staking: DappStakingConfig {
-            reward_portion: vec![
-                Permill::from_percent(40),
-                Permill::from_percent(30),
-                Permill::from_percent(20),
-                Permill::from_percent(10),
-            ],
-            slot_distribution: vec![
-                Permill::from_percent(10),
-                Permill::from_percent(20),
-                Permill::from_percent(30),
-                Permill::from_percent(40),
-            ],
+            reward_portion: dapp_staking_reward_portion(),
+            slot_distribution: dapp_staking_slot_distribution(),
             tier_thresholds: vec![
                 TierThreshold::DynamicTvlAmount {
                     amount: 100 * SBY,
@@ -191,20 +112,33 @@ fn make_genesis(
                 },
                 TierThreshold::FixedTvlAmount { amount: 10 * SBY },
             ],
-            slots_per_tier: vec![10, 20, 30, 40],
+            slots_per_tier: dapp_staking_slots_per_tier(),
         },
-        inflation: InflationConfig {
-            params: InflationParameters::default(),
+        inflation_params: InflationParameters::default(),
+        polkadot_xcm: Default::default(),
+        assets: Default::default(),
+        transaction_payment: Default::default(),
+        evm_chain_id: EVMChainIdConfig { chain_id: 0x51 },
+        council: CouncilConfig {
+            members: vec![],
+            phantom: Default::default(),
         },
-    }
+        technical_committee: TechnicalCommitteeConfig {
+            members: vec![],
+            phantom: Default::default(),
+        },
+        democracy: DemocracyConfig::default(),
+        treasury: TreasuryConfig::default(),
+    )
 }
 
-type AccountPublic = <Signature as Verify>::Signer;
+#[cfg(test)]
+mod tests {
+    use super::super::assert_matches_genesis_fixture;
+    use super::*;
 
-/// Helper function to generate an account ID from seed
-fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
-where
-    AccountPublic: From<<TPublic::Pair as Pair>::Public>,
-{
-    AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+    #[test]
+    fn dev_spec_matches_genesis_fixture() {
+        assert_matches_genesis_fixture(&get_chain_spec(), wasm_binary_unwrap());
+    }
 }